@@ -0,0 +1,2 @@
+import { foo } from './depth-zero';
+export { foo };
@@ -0,0 +1,3 @@
+import { type foo as bar } from './depth-zero';
+export const foo = 1;
+export type { bar };
@@ -0,0 +1,3 @@
+import { type h } from './file-ts-h-used-as-inline-type';
+
+const a: typeof h = 2;
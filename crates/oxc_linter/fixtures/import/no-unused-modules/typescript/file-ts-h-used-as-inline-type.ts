@@ -0,0 +1 @@
+export const h = 2;
@@ -25,6 +25,11 @@ pub struct FailedToParseConfigPropertyError(pub String);
 #[diagnostic()]
 pub struct FailedToParseRuleValueError(pub String, pub &'static str);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse options of rule {0:?} with error {1:?}")]
+#[diagnostic()]
+pub struct FailedToParseRuleOptionsError(pub String, pub String);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error(r#"Failed to parse rule severity, expected one of "allow", "off", "deny", "error" or "warn", but got {0:?}"#)]
 #[diagnostic()]
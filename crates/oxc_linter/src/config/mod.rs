@@ -13,7 +13,7 @@ use crate::{rules::RuleEnum, AllowWarnDeny};
 
 use self::errors::{
     FailedToParseConfigError, FailedToParseConfigJsonError, FailedToParseConfigPropertyError,
-    FailedToParseJsonc,
+    FailedToParseJsonc, FailedToParseRuleOptionsError,
 };
 pub use self::{env::ESLintEnv, rules::ESLintRules, settings::ESLintSettings};
 
@@ -70,12 +70,15 @@ impl ESLintConfig {
         (self.settings, self.env)
     }
 
+    /// # Errors
+    ///
+    /// Returns `Err` if the options of a rule cannot be parsed.
     #[allow(clippy::option_if_let_else)]
     pub fn override_rules(
         &self,
         rules_for_override: &mut FxHashSet<RuleEnum>,
         all_rules: &[RuleEnum],
-    ) {
+    ) -> Result<(), Error> {
         use itertools::Itertools;
         let mut rules_to_replace = vec![];
         let mut rules_to_remove = vec![];
@@ -96,7 +99,8 @@ impl ESLintConfig {
                                 .iter()
                                 .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
                             {
-                                rules_to_replace.push(rule.read_json(rule_config.config.clone()));
+                                rules_to_replace
+                                    .push(read_rule_options(rule, rule_config.config.clone())?);
                             }
                         }
                         AllowWarnDeny::Allow => {
@@ -117,7 +121,8 @@ impl ESLintConfig {
                         rule_configs.iter().find(|r| r.severity.is_warn_deny())
                     {
                         if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            rules_to_replace.push(rule.read_json(rule_config.config.clone()));
+                            rules_to_replace
+                                .push(read_rule_options(rule, rule_config.config.clone())?);
                         }
                     } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
                         if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
@@ -134,12 +139,24 @@ impl ESLintConfig {
         for rule in rules_to_replace {
             rules_for_override.replace(rule);
         }
+        Ok(())
     }
 }
 
+fn read_rule_options(
+    rule: &RuleEnum,
+    config: Option<serde_json::Value>,
+) -> Result<RuleEnum, Error> {
+    rule.try_read_json(config).map_err(|err| {
+        FailedToParseRuleOptionsError(rule.name().to_string(), err.to_string()).into()
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::ESLintConfig;
+    use crate::rules::RULES;
+    use rustc_hash::FxHashSet;
     use serde::Deserialize;
     use std::env;
 
@@ -186,4 +203,27 @@ mod test {
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(env.iter().count(), 1);
     }
+
+    #[test]
+    fn test_override_rules_with_invalid_options() {
+        let config = ESLintConfig::deserialize(&serde_json::json!({
+            "rules": {
+                "jsx-a11y/autocomplete-valid": ["error", { "inputComponents": "Foo" }]
+            }
+        }))
+        .unwrap();
+        let mut rules = FxHashSet::default();
+        let err = config.override_rules(&mut rules, &RULES).unwrap_err();
+        assert!(err.to_string().contains("autocomplete-valid"), "{err}");
+
+        let config = ESLintConfig::deserialize(&serde_json::json!({
+            "rules": {
+                "jsx-a11y/autocomplete-valid": ["error", { "inputComponents": ["Foo"] }]
+            }
+        }))
+        .unwrap();
+        let mut rules = FxHashSet::default();
+        assert!(config.override_rules(&mut rules, &RULES).is_ok());
+        assert_eq!(rules.len(), 1);
+    }
 }
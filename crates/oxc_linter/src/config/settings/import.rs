@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use oxc_resolver::{AliasValue, ResolveOptions};
+use oxc_span::VALID_EXTENSIONS;
+use rustc_hash::FxHashMap;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+/// `settings["import/resolver"]`, shared by every import plugin rule.
+///
+/// Accepts the shapes used with eslint-plugin-import: a resolver name (`"node"`),
+/// resolver options keyed by resolver name (`{ "node": { "extensions": [".js"] } }`),
+/// or the node resolver options directly. Only the node resolver is supported, other
+/// resolvers and malformed options are ignored instead of failing the config.
+///
+/// Relative paths are resolved against the current working directory.
+#[derive(Debug, Deserialize, Default)]
+#[serde(from = "Value")]
+pub struct ImportResolverSettings {
+    /// Extensions tried for extensionless specifiers, e.g. `[".js", ".coffee"]`.
+    /// Defaults to every extension the parser supports.
+    pub extensions: Option<Vec<String>>,
+    /// Specifiers mapped to paths, e.g. `{ "@": "./src" }`.
+    pub alias: FxHashMap<String, String>,
+    /// Directories searched for bare specifiers after `node_modules`, like `NODE_PATH`.
+    pub paths: Vec<String>,
+}
+
+impl From<Value> for ImportResolverSettings {
+    fn from(value: Value) -> Self {
+        // A resolver name, or a list of them, uses the default options.
+        let Value::Object(mut resolvers) = value else { return Self::default() };
+        let options = resolvers.remove("node").unwrap_or(Value::Object(resolvers));
+        Self {
+            extensions: option(&options, "extensions"),
+            alias: option(&options, "alias").unwrap_or_default(),
+            paths: option(&options, "paths").unwrap_or_default(),
+        }
+    }
+}
+
+fn option<T: DeserializeOwned>(options: &Value, name: &str) -> Option<T> {
+    options.get(name).and_then(|value| T::deserialize(value).ok())
+}
+
+impl ImportResolverSettings {
+    pub fn resolve_options(&self, cwd: &Path) -> ResolveOptions {
+        let extensions = self
+            .extensions
+            .clone()
+            .unwrap_or_else(|| VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect());
+        let alias = self
+            .alias
+            .iter()
+            .map(|(key, path)| {
+                let path = cwd.join(path).to_string_lossy().to_string();
+                (key.clone(), vec![AliasValue::Path(path)])
+            })
+            .collect();
+        let defaults = ResolveOptions::default();
+        let modules = defaults
+            .modules
+            .into_iter()
+            .chain(self.paths.iter().map(|path| cwd.join(path).to_string_lossy().to_string()))
+            .collect();
+        ResolveOptions { alias, extensions, modules, ..defaults }
+    }
+}
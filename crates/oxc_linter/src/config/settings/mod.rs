@@ -1,6 +1,10 @@
-use self::{jsx_a11y::ESLintSettingsJSXA11y, next::ESLintSettingsNext, react::ESLintSettingsReact};
+use self::{
+    import::ImportResolverSettings, jsx_a11y::ESLintSettingsJSXA11y, next::ESLintSettingsNext,
+    react::ESLintSettingsReact,
+};
 use serde::Deserialize;
 
+mod import;
 mod jsx_a11y;
 mod next;
 mod react;
@@ -13,6 +17,9 @@ mod react;
 /// But each plugin extends this with their own properties.
 #[derive(Debug, Deserialize, Default)]
 pub struct ESLintSettings {
+    #[serde(default)]
+    #[serde(rename = "import/resolver")]
+    pub import_resolver: ImportResolverSettings,
     #[serde(default)]
     #[serde(rename = "jsx-a11y")]
     pub jsx_a11y: ESLintSettingsJSXA11y,
@@ -37,6 +44,11 @@ mod test {
                     "Link2": "Anchor2"
                 }
             },
+            "import/resolver": {
+                "extensions": [".js", ".coffee"],
+                "alias": { "@": "./src" },
+                "paths": ["./lib"]
+            },
             "next": {
                 "rootDir": "app"
             },
@@ -57,6 +69,12 @@ mod test {
 
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(settings.jsx_a11y.components.get("Link"), Some(&"Anchor".to_string()));
+        assert_eq!(
+            settings.import_resolver.extensions,
+            Some(vec![".js".to_string(), ".coffee".to_string()])
+        );
+        assert_eq!(settings.import_resolver.alias.get("@"), Some(&"./src".to_string()));
+        assert_eq!(settings.import_resolver.paths, vec!["./lib".to_string()]);
         assert!(settings.next.get_root_dirs().contains(&"app".to_string()));
         assert_eq!(settings.react.get_form_component_attrs("CustomForm"), Some(vec![]));
         assert_eq!(
@@ -74,10 +92,47 @@ mod test {
         assert_eq!(settings.react.get_link_component_attrs("Noop"), None);
     }
 
+    #[test]
+    fn test_parse_import_resolver_settings() {
+        let parse = |import_resolver: serde_json::Value| {
+            ESLintSettings::deserialize(&serde_json::json!({ "import/resolver": import_resolver }))
+                .unwrap()
+                .import_resolver
+        };
+
+        let import_resolver = parse(serde_json::json!("node"));
+        assert!(import_resolver.extensions.is_none());
+        assert!(import_resolver.paths.is_empty());
+
+        let import_resolver = parse(serde_json::json!({
+            "node": { "extensions": [".js", ".coffee"], "paths": ["./lib"] },
+            "webpack": { "config": "webpack.config.js" }
+        }));
+        assert_eq!(
+            import_resolver.extensions,
+            Some(vec![".js".to_string(), ".coffee".to_string()])
+        );
+        assert_eq!(import_resolver.paths, vec!["./lib".to_string()]);
+
+        let import_resolver = parse(serde_json::json!({ "node": true }));
+        assert!(import_resolver.extensions.is_none());
+
+        let import_resolver = parse(serde_json::json!({ "webpack": { "extensions": [".js"] } }));
+        assert!(import_resolver.extensions.is_none());
+
+        let import_resolver = parse(serde_json::json!({
+            "extensions": [".js"],
+            "alias": { "map": [["@", "./src"]] }
+        }));
+        assert_eq!(import_resolver.extensions, Some(vec![".js".to_string()]));
+        assert!(import_resolver.alias.is_empty());
+    }
+
     #[test]
     fn test_parse_settings_default() {
         let settings = ESLintSettings::default();
         assert!(settings.jsx_a11y.polymorphic_prop_name.is_none());
         assert!(settings.jsx_a11y.components.is_empty());
+        assert!(settings.import_resolver.extensions.is_none());
     }
 }
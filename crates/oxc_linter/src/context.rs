@@ -2,6 +2,7 @@ use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_diagnostics::Error;
+use oxc_resolver::Resolver;
 use oxc_semantic::{AstNodes, JSDocComment, ScopeTree, Semantic, SymbolTable};
 use oxc_span::SourceType;
 
@@ -9,6 +10,7 @@ use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
     fixer::{Fix, Message},
     javascript_globals::GLOBALS,
+    service::ExportUsages,
     AstNode, ESLintEnv, ESLintSettings,
 };
 
@@ -29,6 +31,13 @@ pub struct LintContext<'a> {
     settings: Arc<ESLintSettings>,
 
     env: Arc<ESLintEnv>,
+
+    /// Shared by all files of a lint run, only set when linting through `LintService`.
+    resolver: Option<Arc<Resolver>>,
+
+    /// Collected once per lint run for `import/no-unused-modules`, only set when linting
+    /// through `LintService`.
+    export_usages: Option<Arc<ExportUsages>>,
 }
 
 impl<'a> LintContext<'a> {
@@ -44,6 +53,8 @@ impl<'a> LintContext<'a> {
             file_path,
             settings: Arc::new(ESLintSettings::default()),
             env: Arc::new(ESLintEnv::default()),
+            resolver: None,
+            export_usages: None,
         }
     }
 
@@ -65,6 +76,18 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_resolver(mut self, resolver: &Arc<Resolver>) -> Self {
+        self.resolver = Some(Arc::clone(resolver));
+        self
+    }
+
+    #[must_use]
+    pub fn with_export_usages(mut self, export_usages: &Arc<ExportUsages>) -> Self {
+        self.export_usages = Some(Arc::clone(export_usages));
+        self
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -77,6 +100,16 @@ impl<'a> LintContext<'a> {
         &self.settings
     }
 
+    /// The resolver configured from `settings["import/resolver"]`.
+    pub fn resolver(&self) -> Option<&Resolver> {
+        self.resolver.as_deref()
+    }
+
+    /// The exports used by the `src` files of `import/no-unused-modules` in this lint run.
+    pub fn export_usages(&self) -> Option<&ExportUsages> {
+        self.export_usages.as_deref()
+    }
+
     pub fn source_text(&self) -> &'a str {
         self.semantic().source_text()
     }
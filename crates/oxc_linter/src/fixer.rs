@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 
+use oxc_ast::ast::JSXAttribute;
 use oxc_diagnostics::Error;
 use oxc_span::Span;
 
+use crate::context::LintContext;
+
 #[derive(Debug, Default)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
@@ -19,6 +22,15 @@ impl<'a> Fix<'a> {
     }
 }
 
+/// Removes `attr` from its element, together with the whitespace separating it from the
+/// previous token, so that `<div a b c />` becomes `<div b c />` or `<div a c />` or `<div a b />`.
+pub fn remove_jsx_attribute<'a>(attr: &JSXAttribute, ctx: &LintContext<'a>) -> Fix<'a> {
+    let before = &ctx.source_text()[..attr.span.start as usize];
+    #[allow(clippy::cast_possible_truncation)]
+    let start = before.trim_end().len() as u32;
+    Fix::delete(Span::new(start, attr.span.end))
+}
+
 #[derive(Debug)]
 pub struct FixResult<'a> {
     pub fixed: bool,
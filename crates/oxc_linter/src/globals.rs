@@ -199,6 +199,97 @@ pub const VALID_ARIA_ROLES: phf::Set<&'static str> = phf_set! {
   "graphics-symbol"
 };
 
+/// set of ARIA roles describing interactive widgets (`toolbar` included, as in eslint-plugin-jsx-a11y)
+/// Reference: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/isInteractiveRole.js>
+pub const INTERACTIVE_ROLES: phf::Set<&'static str> = phf_set! {
+  "button",
+  "checkbox",
+  "columnheader",
+  "combobox",
+  "grid",
+  "gridcell",
+  "link",
+  "listbox",
+  "menu",
+  "menubar",
+  "menuitem",
+  "menuitemcheckbox",
+  "menuitemradio",
+  "option",
+  "radio",
+  "radiogroup",
+  "row",
+  "rowheader",
+  "scrollbar",
+  "searchbox",
+  "slider",
+  "spinbutton",
+  "switch",
+  "tab",
+  "tablist",
+  "textbox",
+  "toolbar",
+  "tree",
+  "treegrid",
+  "treeitem"
+};
+
+/// Elements whose implicit role is a non-interactive (document structure or landmark) role.
+/// Disjoint from the interactive content accepted by `utils::is_interactive_element`.
+/// Reference: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/isNonInteractiveElement.js>
+pub const NON_INTERACTIVE_ELEMENTS: phf::Set<&'static str> = phf_set! {
+  "article",
+  "aside",
+  "blockquote",
+  "body",
+  "br",
+  "caption",
+  "dd",
+  "dfn",
+  "dialog",
+  "dir",
+  "dl",
+  "dt",
+  "fieldset",
+  "figcaption",
+  "figure",
+  "footer",
+  "form",
+  "h1",
+  "h2",
+  "h3",
+  "h4",
+  "h5",
+  "h6",
+  "hr",
+  "html",
+  "img",
+  "legend",
+  "li",
+  "main",
+  "mark",
+  "marquee",
+  "menu",
+  "meter",
+  "nav",
+  "ol",
+  "optgroup",
+  "output",
+  "p",
+  "pre",
+  "progress",
+  "ruby",
+  "section",
+  "table",
+  "tbody",
+  "td",
+  "tfoot",
+  "thead",
+  "time",
+  "tr",
+  "ul",
+};
+
 pub const HTML_TAG: phf::Set<&'static str> = phf_set! {
     "a",
     "abbr",
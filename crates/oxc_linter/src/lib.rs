@@ -94,6 +94,10 @@ impl Linter {
         &self.options
     }
 
+    pub fn settings(&self) -> &ESLintSettings {
+        &self.settings
+    }
+
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
     }
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use oxc_semantic::SymbolId;
+use serde::de::DeserializeOwned;
 
 use crate::{context::LintContext, AstNode};
 
@@ -10,6 +11,15 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Initialize from eslint json configuration, reporting invalid options instead of ignoring them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the configuration does not match the options of the rule.
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        Ok(Self::from_configuration(value))
+    }
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
@@ -20,6 +30,24 @@ pub trait Rule: Sized + Default + fmt::Debug {
     fn run_once(&self, _ctx: &LintContext) {}
 }
 
+/// Deserialize the options of a rule from the first element of its eslint json configuration,
+/// e.g. `{ "allow": ["warn"] }` in `"no-console": ["error", { "allow": ["warn"] }]`.
+///
+/// Missing options fall back to `T::default()`.
+///
+/// # Errors
+///
+/// Returns `Err` if the options do not match the shape of `T`.
+pub fn deserialize_rule_options<T>(value: &serde_json::Value) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned + Default,
+{
+    match value.get(0) {
+        None | Some(serde_json::Value::Null) => Ok(T::default()),
+        Some(options) => T::deserialize(options),
+    }
+}
+
 pub trait RuleMeta {
     const NAME: &'static str;
 
@@ -84,8 +112,46 @@ impl fmt::Display for RuleCategory {
 
 #[cfg(test)]
 mod test {
+    use serde::Deserialize;
+
+    use super::deserialize_rule_options;
     use crate::RULES;
 
+    #[derive(Debug, Default, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase", default)]
+    struct Options {
+        allow_foo: bool,
+        names: Vec<String>,
+    }
+
+    #[test]
+    fn deserialize_options() {
+        let options: Options =
+            deserialize_rule_options(&serde_json::json!([{ "allowFoo": true, "names": ["a"] }]))
+                .unwrap();
+        assert_eq!(options, Options { allow_foo: true, names: vec!["a".to_string()] });
+
+        let options: Options =
+            deserialize_rule_options(&serde_json::json!([{ "names": ["a"] }])).unwrap();
+        assert_eq!(options, Options { allow_foo: false, names: vec!["a".to_string()] });
+    }
+
+    #[test]
+    fn deserialize_missing_options() {
+        let options: Options = deserialize_rule_options(&serde_json::json!([])).unwrap();
+        assert_eq!(options, Options::default());
+        let options: Options = deserialize_rule_options(&serde_json::Value::Null).unwrap();
+        assert_eq!(options, Options::default());
+    }
+
+    #[test]
+    fn deserialize_invalid_options() {
+        let err = deserialize_rule_options::<Options>(&serde_json::json!([{ "names": "a" }]))
+            .unwrap_err();
+        assert!(err.to_string().contains("expected a sequence"), "{err}");
+        assert!(deserialize_rule_options::<Options>(&serde_json::json!(["foo"])).is_err());
+    }
+
     #[test]
     fn ensure_documentation() {
         assert!(!RULES.is_empty());
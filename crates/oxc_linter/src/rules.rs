@@ -7,14 +7,25 @@
 
 /// <https://github.com/import-js/eslint-plugin-import>
 mod import {
+    pub mod consistent_type_specifier_style;
     pub mod default;
     pub mod export;
+    pub mod first;
     pub mod named;
     pub mod no_amd;
+    pub mod no_commonjs;
     pub mod no_cycle;
+    pub mod no_duplicates;
+    pub mod no_empty_named_blocks;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
+    pub mod no_nodejs_modules;
+    pub mod no_relative_packages;
+    pub mod no_restricted_paths;
     pub mod no_self_import;
+    pub mod no_unassigned_import;
+    pub mod no_unresolved;
+    pub mod no_unused_modules;
 }
 
 mod deepscan {
@@ -259,7 +270,9 @@ mod unicorn {
 }
 
 mod jsx_a11y {
+    pub mod accessible_emoji;
     pub mod alt_text;
+    pub mod anchor_ambiguous_text;
     pub mod anchor_has_content;
     pub mod anchor_is_valid;
     pub mod aria_activedescendant_has_tabindex;
@@ -279,6 +292,10 @@ mod jsx_a11y {
     pub mod no_aria_hidden_on_focusable;
     pub mod no_autofocus;
     pub mod no_distracting_elements;
+    pub mod no_interactive_element_to_noninteractive_role;
+    pub mod no_noninteractive_element_interactions;
+    pub mod no_noninteractive_element_to_interactive_role;
+    pub mod no_noninteractive_tabindex;
     pub mod no_redundant_roles;
     pub mod prefer_tag_over_role;
     pub mod role_has_required_aria_props;
@@ -538,14 +555,26 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_array_as_prop,
     react_perf::jsx_no_new_function_as_prop,
     react_perf::jsx_no_new_object_as_prop,
+    import::consistent_type_specifier_style,
     import::default,
     import::no_named_as_default_member,
     import::no_named_as_default,
     import::named,
     import::no_cycle,
+    import::no_duplicates,
+    import::no_empty_named_blocks,
     import::no_self_import,
+    import::no_unassigned_import,
+    import::no_relative_packages,
+    import::no_nodejs_modules,
+    import::no_restricted_paths,
+    import::no_unresolved,
+    import::no_unused_modules,
     import::no_amd,
+    import::no_commonjs,
     import::export,
+    import::first,
+    jsx_a11y::accessible_emoji,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
@@ -572,6 +601,11 @@ oxc_macros::declare_all_lint_rules! {
     jsx_a11y::no_distracting_elements,
     jsx_a11y::role_supports_aria_props,
     jsx_a11y::autocomplete_valid,
+    jsx_a11y::no_noninteractive_element_to_interactive_role,
+    jsx_a11y::no_interactive_element_to_noninteractive_role,
+    jsx_a11y::no_noninteractive_tabindex,
+    jsx_a11y::anchor_ambiguous_text,
+    jsx_a11y::no_noninteractive_element_interactions,
     oxc::approx_constant,
     oxc::const_comparisons,
     oxc::double_comparisons,
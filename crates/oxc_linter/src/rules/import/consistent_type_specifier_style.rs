@@ -0,0 +1,248 @@
+use itertools::Itertools;
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ImportSpecifier},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum ConsistentTypeSpecifierStyleDiagnostic {
+    #[error("eslint-plugin-import(consistent-type-specifier-style): Prefer using inline type specifiers instead of a top-level type-only import.")]
+    #[diagnostic(
+        severity(warning),
+        help("Use `import {{ type A }}` instead of `import type {{ A }}`")
+    )]
+    PreferInline(#[label] Span),
+    #[error("eslint-plugin-import(consistent-type-specifier-style): Prefer using a top-level type-only import instead of inline type specifiers.")]
+    #[diagnostic(
+        severity(warning),
+        help("Use `import type {{ A }}` instead of `import {{ type A }}`")
+    )]
+    PreferTopLevel(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Inline,
+    #[default]
+    TopLevel,
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/consistent-type-specifier-style.md>
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentTypeSpecifierStyle {
+    style: Style,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a consistent style for type-only imports: either inline type specifiers
+    /// (`import { type A }`) or a top-level type-only import (`import type { A }`).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mixing both styles makes it harder to see at a glance which imports are erased at compile time.
+    ///
+    /// ### Options
+    ///
+    /// `"prefer-top-level"` (default) or `"prefer-inline"`.
+    ///
+    /// ### Example
+    ///
+    /// ```typescript
+    /// // fail with "prefer-top-level"
+    /// import { type A, type B } from 'mod';
+    /// import { a, type B } from 'mod';
+    /// // fail with "prefer-inline"
+    /// import type { A, B } from 'mod';
+    /// ```
+    ConsistentTypeSpecifierStyle,
+    style
+);
+
+impl Rule for ConsistentTypeSpecifierStyle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let style = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("prefer-inline") => Style::Inline,
+            _ => Style::TopLevel,
+        };
+        Self { style }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ImportDeclaration(import_decl) = node.kind() else { return };
+        let Some(specifiers) = &import_decl.specifiers else { return };
+        if specifiers.is_empty() {
+            return;
+        }
+
+        match self.style {
+            Style::Inline => {
+                if import_decl.import_kind.is_value() {
+                    return;
+                }
+                // `import type Default from 'mod'` and `import type * as NS from 'mod'`
+                // have no inline equivalent.
+                let Some(named) = as_named_specifiers(specifiers) else { return };
+                ctx.diagnostic_with_fix(
+                    ConsistentTypeSpecifierStyleDiagnostic::PreferInline(import_decl.span),
+                    || {
+                        let specifiers = named
+                            .iter()
+                            .map(|specifier| {
+                                format!("type {}", specifier.span.source_text(ctx.source_text()))
+                            })
+                            .join(", ");
+                        Fix::new(
+                            format!("import {{ {specifiers} }} from "),
+                            Span::new(import_decl.span.start, import_decl.source.span.start),
+                        )
+                    },
+                );
+            }
+            Style::TopLevel => {
+                if import_decl.import_kind.is_type() {
+                    return;
+                }
+                let (type_specifiers, value_specifiers): (Vec<_>, Vec<_>) =
+                    specifiers.iter().partition(|specifier| {
+                        matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(specifier) if specifier.import_kind.is_type())
+                    });
+                if type_specifiers.is_empty() {
+                    return;
+                }
+                ctx.diagnostic_with_fix(
+                    ConsistentTypeSpecifierStyleDiagnostic::PreferTopLevel(import_decl.span),
+                    || fix_to_top_level(import_decl, &type_specifiers, &value_specifiers, ctx),
+                );
+            }
+        }
+    }
+}
+
+fn as_named_specifiers(specifiers: &[ImportDeclarationSpecifier]) -> Option<Vec<&ImportSpecifier>> {
+    specifiers
+        .iter()
+        .map(|specifier| match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => Some(specifier),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Moves the inline type specifiers into a top-level type-only import.
+/// When value specifiers are present, they are kept in their own import statement
+/// because a mixed import cannot be expressed as `import type`.
+fn fix_to_top_level<'a>(
+    import_decl: &ImportDeclaration,
+    type_specifiers: &[&ImportDeclarationSpecifier],
+    value_specifiers: &[&ImportDeclarationSpecifier],
+    ctx: &LintContext<'a>,
+) -> Fix<'a> {
+    let source_text = ctx.source_text();
+    // `'mod';` including import attributes
+    let tail =
+        Span::new(import_decl.source.span.start, import_decl.span.end).source_text(source_text);
+
+    let type_names = type_specifiers
+        .iter()
+        .map(|specifier| {
+            let text = specifier.span().source_text(source_text);
+            text.strip_prefix("type").map_or(text, str::trim_start)
+        })
+        .join(", ");
+    let type_import = format!("import type {{ {type_names} }} from {tail}");
+
+    if value_specifiers.is_empty() {
+        return Fix::new(type_import, import_decl.span);
+    }
+
+    let mut clauses = vec![];
+    let mut named = vec![];
+    for specifier in value_specifiers {
+        let text = specifier.span().source_text(source_text);
+        match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(_) => named.push(text),
+            _ => clauses.push(text.to_string()),
+        }
+    }
+    if !named.is_empty() {
+        clauses.push(format!("{{ {} }}", named.join(", ")));
+    }
+    let value_import = format!("import {} from {tail}", clauses.join(", "));
+
+    Fix::new(format!("{value_import}\n{type_import}"), import_decl.span)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import Foo from 'Foo';", None),
+        ("import type Foo from 'Foo';", None),
+        ("import { Foo } from 'Foo';", None),
+        ("import { Foo as Bar } from 'Foo';", None),
+        ("import * as Foo from 'Foo';", None),
+        ("import 'Foo';", None),
+        ("import {} from 'Foo';", None),
+        ("import type {} from 'Foo';", None),
+        ("import type { Foo } from 'Foo';", None),
+        ("import type { Foo as Bar } from 'Foo';", None),
+        ("import type { Foo, Bar, Baz, Bam } from 'Foo';", None),
+        ("import type { Foo } from 'Foo';", Some(json!(["prefer-top-level"]))),
+        ("import { Foo } from 'Foo';", Some(json!(["prefer-inline"]))),
+        ("import { type Foo } from 'Foo';", Some(json!(["prefer-inline"]))),
+        ("import { type Foo as Bar } from 'Foo';", Some(json!(["prefer-inline"]))),
+        ("import { type Foo, type Bar, Baz, Bam } from 'Foo';", Some(json!(["prefer-inline"]))),
+        ("import type Foo from 'Foo';", Some(json!(["prefer-inline"]))),
+        ("import type * as Foo from 'Foo';", Some(json!(["prefer-inline"]))),
+    ];
+
+    let fail = vec![
+        ("import { type Foo } from 'Foo';", None),
+        ("import { type Foo as Bar } from 'Foo';", None),
+        ("import { type Foo, type Bar } from 'Foo';", None),
+        ("import { Foo, type Bar } from 'Foo';", None),
+        ("import Foo, { type Bar } from 'Foo';", Some(json!(["prefer-top-level"]))),
+        ("import type { Foo } from 'Foo';", Some(json!(["prefer-inline"]))),
+        ("import type { Foo, Bar, Baz } from 'Foo';", Some(json!(["prefer-inline"]))),
+    ];
+
+    let fix = vec![
+        ("import { type Foo } from 'Foo';", "import type { Foo } from 'Foo';", None),
+        ("import { type Foo as Bar } from 'Foo';", "import type { Foo as Bar } from 'Foo';", None),
+        ("import { type Foo, type Bar } from 'Foo';", "import type { Foo, Bar } from 'Foo';", None),
+        (
+            "import { Foo, type Bar, Baz, type Bam } from 'Foo';",
+            "import { Foo, Baz } from 'Foo';\nimport type { Bar, Bam } from 'Foo';",
+            None,
+        ),
+        (
+            "import Foo, { type Bar } from 'Foo';",
+            "import Foo from 'Foo';\nimport type { Bar } from 'Foo';",
+            None,
+        ),
+        (
+            "import type { Foo, Bar as Baz } from 'Foo';",
+            "import { type Foo, type Bar as Baz } from 'Foo';",
+            Some(json!(["prefer-inline"])),
+        ),
+        (
+            "import type { Foo } from 'Foo'",
+            "import { type Foo } from 'Foo'",
+            Some(json!(["prefer-inline"])),
+        ),
+    ];
+
+    Tester::new(ConsistentTypeSpecifierStyle::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -16,6 +16,9 @@ enum ExportDiagnostic {
     #[error("eslint-plugin-import(export): Multiple exports of name '{1}'.")]
     #[diagnostic(severity(warning))]
     MultipleNamedExport(#[label] Span, Atom),
+    #[error("eslint-plugin-import(export): Multiple default exports.")]
+    #[diagnostic(severity(warning))]
+    MultipleDefaultExports(#[label] Span),
     #[error("eslint-plugin-import(export): No named exports found in module '{1}'")]
     #[diagnostic(severity(warning))]
     NoNamedExport(#[label] Span, Atom),
@@ -29,11 +32,16 @@ declare_oxc_lint!(
     /// ### What it does
     /// Reports funny business with exports, like repeated exports of names or defaults.
     ///
+    /// This includes names provided by more than one `export * from` declaration,
+    /// which are silently dropped from the module namespace.
+    ///
     /// ### Example
     /// ```javascript
     /// let foo;
     /// export { foo }; // Multiple exports of name 'foo'.
     /// export * from "./export-all" // export-all.js also export foo
+    /// export default foo;
+    /// export { bar as default }; // Multiple default exports.
     /// ```
     Export,
     nursery
@@ -42,11 +50,11 @@ declare_oxc_lint!(
 impl Rule for Export {
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.semantic().module_record();
+        check_local_exports(module_record, ctx);
+
         let named_export = &module_record.exported_bindings;
         let mut duplicated_named_export = FxHashMap::default();
-        if module_record.star_export_entries.is_empty() {
-            return;
-        }
+        let mut star_exported_names: FxHashMap<Atom, Vec<Span>> = FxHashMap::default();
         for export_entry in &module_record.star_export_entries {
             let Some(module_request) = &export_entry.module_request else {
                 continue;
@@ -69,9 +77,15 @@ impl Rule for Export {
                 continue;
             }
 
-            for name in &all_export_names {
-                if let Some(span) = named_export.get(name) {
+            for name in all_export_names {
+                // `export *` never re-exports the default export.
+                if name == "default" {
+                    continue;
+                }
+                if let Some(span) = named_export.get(&name) {
                     duplicated_named_export.entry(*span).or_insert_with(|| name.clone());
+                } else {
+                    star_exported_names.entry(name).or_default().push(module_request.span());
                 }
             }
         }
@@ -79,6 +93,47 @@ impl Rule for Export {
         for (span, name) in duplicated_named_export {
             ctx.diagnostic(ExportDiagnostic::MultipleNamedExport(span, name));
         }
+
+        // `export * from './a'; export * from './b'` where both modules export the same name
+        let mut star_exported_names = star_exported_names
+            .into_iter()
+            .filter(|(_, spans)| spans.len() > 1)
+            .collect::<Vec<_>>();
+        star_exported_names.sort_unstable_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        for (name, spans) in star_exported_names {
+            for span in spans {
+                ctx.diagnostic(ExportDiagnostic::MultipleNamedExport(span, name.clone()));
+            }
+        }
+    }
+}
+
+/// Reports names and defaults exported more than once by the module's own export declarations.
+fn check_local_exports(module_record: &ModuleRecord, ctx: &LintContext<'_>) {
+    let mut default_spans = module_record.export_default_duplicated.clone();
+    default_spans.extend(module_record.export_default);
+    default_spans.extend(module_record.exported_bindings.get("default"));
+
+    let mut reported = FxHashSet::default();
+    for name_span in &module_record.exported_bindings_duplicated {
+        let name = name_span.name();
+        if name.as_str() == "default" {
+            default_spans.push(name_span.span());
+            continue;
+        }
+        ctx.diagnostic(ExportDiagnostic::MultipleNamedExport(name_span.span(), name.clone()));
+        if reported.insert(name) {
+            ctx.diagnostic(ExportDiagnostic::MultipleNamedExport(
+                module_record.exported_bindings[name],
+                name.clone(),
+            ));
+        }
+    }
+
+    if default_spans.len() > 1 {
+        for span in default_spans {
+            ctx.diagnostic(ExportDiagnostic::MultipleDefaultExports(span));
+        }
     }
 }
 
@@ -221,6 +276,8 @@ fn test() {
                 export namespace Foo { }
             "),
             (r#"export * from "./file1.ts""#),
+            ("export * from './named-export-collision/a'; export * from './export-all'"),
+            ("export const a = 1; export * from './named-export-collision/a'"),
             ("
                 export * as A from './named-export-collision/a';
                 export * as B from './named-export-collision/b';
@@ -246,6 +303,14 @@ fn test() {
         ];
         let fail = vec![
             (r#"let foo; export { foo }; export * from "./export-all""#),
+            ("let a; export { a }; export { a }"),
+            ("export const a = 1; export { b as a }; let b;"),
+            ("export default 1; export default 2;"),
+            ("let foo; export default foo; export { foo as default }"),
+            ("
+                export * from './named-export-collision/a';
+                export * from './named-export-collision/b';
+            "),
             // (r#"export * from "./malformed.js""#),
             // This case has been comment out in eslint-plugin-import
             // https://github.com/import-js/eslint-plugin-import/blob/7a21f7e10f18c04473faadca94928af6b8e28009/tests/src/rules/export.js#L101-L109
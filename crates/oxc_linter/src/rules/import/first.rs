@@ -0,0 +1,246 @@
+use oxc_ast::{
+    ast::{Declaration, ModuleDeclaration, Statement, TSModuleReference},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum FirstDiagnostic {
+    #[error("eslint-plugin-import(first): Import in body of module; reorder to top.")]
+    #[diagnostic(severity(warning))]
+    Import(#[label] Span),
+    #[error("eslint-plugin-import(first): Re-export in body of module; reorder to top.")]
+    #[diagnostic(severity(warning))]
+    ReExport(#[label] Span),
+}
+
+/// The role of a top-level statement in the import ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatementKind {
+    Import,
+    /// `export ... from '...'`, only with `checkReExports`.
+    ReExport,
+    Other,
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/first.md>
+#[derive(Debug, Default, Clone)]
+pub struct First {
+    check_re_exports: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports any imports that come after non-import statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Imports are hoisted, so code placed above them still runs after the imported modules
+    /// have been evaluated. Keeping all imports at the top makes the evaluation order obvious.
+    ///
+    /// ### Options
+    ///
+    /// * `checkReExports`: also treat `export ... from '...'` re-exports as imports, so that
+    ///   statements placed between re-exports (e.g. in barrel files) are reported.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// import foo from './foo'
+    /// initWith(foo)
+    /// import bar from './bar'
+    /// // pass
+    /// import foo from './foo'
+    /// import bar from './bar'
+    /// initWith(foo)
+    /// ```
+    First,
+    style
+);
+
+impl Rule for First {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let check_re_exports = value
+            .as_array()
+            .and_then(|arr| arr.iter().find_map(|v| v.get("checkReExports")))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { check_re_exports }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(root) = ctx.nodes().iter().next() else { return };
+        let AstKind::Program(program) = root.kind() else { return };
+
+        // The directive prologue is kept in `program.directives`, so a string literal
+        // statement in the body is an ordinary statement.
+        let kinds = program
+            .body
+            .iter()
+            .map(|statement| (statement, self.classify(statement)))
+            .collect::<Vec<_>>();
+        // Imports before the first other statement are in place, the ones after are misplaced.
+        let leading_len =
+            kinds.iter().position(|(_, kind)| *kind == StatementKind::Other).unwrap_or(kinds.len());
+        let (leading, rest) = kinds.split_at(leading_len);
+        let misplaced =
+            rest.iter().filter(|(_, kind)| *kind != StatementKind::Other).collect::<Vec<_>>();
+
+        let Some((last_misplaced, _)) = misplaced.last() else { return };
+        let last_leading_import = leading.last().map(|(statement, _)| statement.span());
+        // Misplaced statements are moved after the leading imports, or to the top of the module.
+        let insert_at =
+            last_leading_import.map_or_else(|| program.body[0].span().start, |span| span.end);
+        let fix_span = Span::new(insert_at, last_misplaced.span().end);
+
+        for (i, (statement, kind)) in misplaced.iter().enumerate() {
+            let diagnostic = if *kind == StatementKind::ReExport {
+                FirstDiagnostic::ReExport(statement.span())
+            } else {
+                FirstDiagnostic::Import(statement.span())
+            };
+            // One fix moves every misplaced statement, attach it to the first diagnostic only.
+            if i > 0 {
+                ctx.diagnostic(diagnostic);
+                continue;
+            }
+            ctx.diagnostic_with_fix(diagnostic, || {
+                let source_text = ctx.source_text();
+                let mut moved = vec![];
+                let mut rest = String::new();
+                let mut cursor = insert_at;
+                for (statement, _) in &misplaced {
+                    let span = statement.span();
+                    rest.push_str(
+                        Span::new(cursor, span.start).source_text(source_text).trim_end(),
+                    );
+                    moved.push(span.source_text(source_text));
+                    cursor = span.end;
+                }
+                let moved = moved.join("\n");
+                let content = if last_leading_import.is_some() {
+                    format!("\n{moved}{rest}")
+                } else {
+                    format!("{moved}\n{rest}")
+                };
+                Fix::new(content, fix_span)
+            });
+        }
+    }
+}
+
+impl First {
+    fn classify(&self, statement: &Statement) -> StatementKind {
+        match statement {
+            Statement::ModuleDeclaration(decl) => match &**decl {
+                ModuleDeclaration::ImportDeclaration(_) => StatementKind::Import,
+                ModuleDeclaration::ExportNamedDeclaration(decl)
+                    if self.check_re_exports && decl.source.is_some() =>
+                {
+                    StatementKind::ReExport
+                }
+                ModuleDeclaration::ExportAllDeclaration(_) if self.check_re_exports => {
+                    StatementKind::ReExport
+                }
+                _ => StatementKind::Other,
+            },
+            // `import foo = require('foo')`
+            Statement::Declaration(Declaration::TSImportEqualsDeclaration(decl))
+                if matches!(
+                    *decl.module_reference,
+                    TSModuleReference::ExternalModuleReference(_)
+                ) =>
+            {
+                StatementKind::Import
+            }
+            _ => StatementKind::Other,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let check_re_exports = || Some(json!([{ "checkReExports": true }]));
+
+    let pass = vec![
+        ("import { x } from './foo'; import { y } from './bar';\nexport { x, y }", None),
+        ("import { x } from 'foo'; import { y } from './bar'", None),
+        ("'use directive';\nimport { x } from 'foo';", None),
+        (
+            "'use client';\n'use strict';\nimport { x } from 'foo';\nimport { y } from './bar';",
+            None,
+        ),
+        ("'use strict';\nimport { x } from 'foo';\nconst y = 'use strict';", None),
+        ("import x = require('x');\nimport { y } from './bar';", None),
+        ("import x = require('x');\nconst y = x;", None),
+        ("const A = 1;\nimport x = A.B;", None),
+        ("export { x } from './x';\nconst y = 1;\nexport { z } from './z';", None),
+        ("export { x } from './x';\nexport * from './y';\nconst z = 1;", check_re_exports()),
+        (
+            "import x from './x';\nexport { y } from './y';\nconst z = x;\nexport { z };",
+            check_re_exports(),
+        ),
+    ];
+
+    let fail = vec![
+        ("import { x } from './foo';\nexport { x };\nimport { y } from './bar';", None),
+        ("import { x } from './foo';\nexport { x };\nimport { y } from './bar';\nimport { z } from './baz';", None),
+        ("var a = 1;\nimport { y } from './bar';", None),
+        ("if (true) { console.log(1) }\nimport a from 'b'", None),
+        ("import a from 'a';\n'use directive';\nimport b from 'b';", None),
+        ("'use strict';\nimport a from 'a';\n'use directive';\nimport b from 'b';", None),
+        ("'use strict';\nvar a = 1;\nimport b from 'b';", None),
+        ("var a = 1;\nimport x = require('x');", None),
+        ("export { x } from './x';\nconst y = 1;\nexport { z } from './z';", check_re_exports()),
+        ("export * from './x';\nconst y = 1;\nexport * as z from './z';", check_re_exports()),
+        ("import x from './x';\nconst y = x;\nexport { z } from './z';", check_re_exports()),
+    ];
+
+    let fix = vec![
+        (
+            "import { x } from './foo';\nexport { x };\nimport { y } from './bar';",
+            "import { x } from './foo';\nimport { y } from './bar';\nexport { x };",
+            None,
+        ),
+        (
+            "import { x } from './foo';\nexport { x };\nimport { y } from './bar';\nimport { z } from './baz';",
+            "import { x } from './foo';\nimport { y } from './bar';\nimport { z } from './baz';\nexport { x };",
+            None,
+        ),
+        ("var a = 1;\nimport { y } from './bar';", "import { y } from './bar';\nvar a = 1;", None),
+        (
+            "'use strict';\nimport a from 'a';\n'use directive';\nimport b from 'b';",
+            "'use strict';\nimport a from 'a';\nimport b from 'b';\n'use directive';",
+            None,
+        ),
+        (
+            "'use strict';\nvar a = 1;\nimport b from 'b';",
+            "'use strict';\nimport b from 'b';\nvar a = 1;",
+            None,
+        ),
+        (
+            "export { a } from './a';\nconst b = 1;\nexport { c } from './c';\nexport * from './d';",
+            "export { a } from './a';\nexport { c } from './c';\nexport * from './d';\nconst b = 1;",
+            check_re_exports(),
+        ),
+        (
+            "const b = 1;\nexport { a } from './a';",
+            "export { a } from './a';\nconst b = 1;",
+            check_re_exports(),
+        ),
+    ];
+
+    Tester::new(First::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -3,8 +3,11 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
+use std::path::PathBuf;
+
 use oxc_span::{Atom, Span};
-use oxc_syntax::module_record::{ExportImportName, ImportImportName};
+use oxc_syntax::module_record::{ExportImportName, ImportImportName, ModuleRecord};
+use rustc_hash::FxHashSet;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -20,10 +23,26 @@ pub struct Named;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Verifies that all named imports and re-exports are part of the set of named exports
+    /// of the resolved module, including names re-exported with `export * from`.
+    ///
+    /// Modules that can't be resolved or parsed, type-only imports, and TypeScript files
+    /// (which are checked by the compiler) are ignored.
+    ///
     /// ### Why is this bad?
     ///
+    /// Importing a name that isn't exported gives `undefined` at runtime, or fails to link
+    /// in native ES modules.
+    ///
     /// ### Example
     /// ```javascript
+    /// // ./foo.js
+    /// export const foo = 'foo'
+    ///
+    /// // fail
+    /// import { bar } from './foo'
+    /// // pass
+    /// import { foo } from './foo'
     /// ```
     Named,
     nursery
@@ -52,7 +71,7 @@ impl Rule for Named {
             };
             let remote_module_record = remote_module_record_ref.value();
             // Check remote bindings
-            if remote_module_record.exported_bindings.contains_key(import_name.name()) {
+            if has_export(remote_module_record, import_name.name(), &mut FxHashSet::default()) {
                 continue;
             }
             ctx.diagnostic(NamedDiagnostic(
@@ -76,7 +95,7 @@ impl Rule for Named {
             };
             let remote_module_record = remote_module_record_ref.value();
             // Check remote bindings
-            if remote_module_record.exported_bindings.contains_key(import_name.name()) {
+            if has_export(remote_module_record, import_name.name(), &mut FxHashSet::default()) {
                 continue;
             }
             ctx.diagnostic(NamedDiagnostic(
@@ -88,6 +107,27 @@ impl Rule for Named {
     }
 }
 
+/// Whether `module_record` exports `name`, directly or through `export * from`.
+/// Names behind star exports that could not be resolved are assumed to exist.
+fn has_export(module_record: &ModuleRecord, name: &Atom, visited: &mut FxHashSet<PathBuf>) -> bool {
+    if module_record.exported_bindings.contains_key(name) {
+        return true;
+    }
+    // `export *` never re-exports the default export.
+    if name.as_str() == "default" || !visited.insert(module_record.resolved_absolute_path.clone()) {
+        return false;
+    }
+    module_record.star_export_entries.iter().any(|export_entry| {
+        let Some(module_request) = &export_entry.module_request else { return false };
+        module_record
+            .loaded_modules
+            .get(module_request.name())
+            .map_or(true, |remote_module_record| {
+                has_export(remote_module_record.value(), name, visited)
+            })
+    })
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -103,11 +143,11 @@ fn test() {
         "import { destructingAssign } from './named-exports'",
         "import { destructingRenamedAssign } from './named-exports'",
         "import { ActionTypes } from './qc'",
-        // TODO: export *
-        // "import {a, b, c, d} from './re-export'",
+        "import {a, b, c, d} from './re-export'",
+        // TODO: module.exports
         // "import {a, b, c} from './re-export-common-star'",
-        // "import {RuleTester} from './re-export-node_modules'",
-        // "import { jsxFoo } from './jsx/AnotherComponent'",
+        "import {RuleTester} from './re-export-node_modules'",
+        "import { jsxFoo } from './jsx/AnotherComponent'",
         "import {a, b, d} from './common'; // eslint-disable-line named",
         "import { foo, bar } from './re-export-names'",
         // TODO: module.exports
@@ -161,7 +201,8 @@ fn test() {
         "import { 'foo' as foo } from './bar'",
         "import { 'foo' as foo } from './empty-module'",
         // export all
-        // "import { foo } from './export-all'",
+        "import { foo } from './export-all'",
+        "export { foo } from './export-all'",
         // TypeScript export assignment
         "import x from './typescript-export-assign-object'",
     ];
@@ -0,0 +1,248 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::is_global_reference, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoCommonjsDiagnostic {
+    #[error("eslint-plugin-import(no-commonjs): Expected \"import\" instead of \"require()\"")]
+    #[diagnostic(severity(warning))]
+    Import(#[label] Span),
+    #[error("eslint-plugin-import(no-commonjs): Expected \"export\" or \"export default\"")]
+    #[diagnostic(severity(warning))]
+    Export(#[label] Span),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-commonjs.md>
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_field_names)]
+pub struct NoCommonjs {
+    allow_primitive_modules: bool,
+    allow_require: bool,
+    allow_conditional_require: bool,
+}
+
+impl Default for NoCommonjs {
+    fn default() -> Self {
+        Self {
+            allow_primitive_modules: false,
+            allow_require: false,
+            allow_conditional_require: true,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports `require([string])` function calls and `module.exports` / `exports.*` assignments.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// CommonJS is not statically analyzable, so it defeats tree shaking and tools relying on
+    /// ES module syntax. Codebases that have migrated to ES modules should not reintroduce it.
+    ///
+    /// ### Options
+    ///
+    /// * `allowPrimitiveModules`: allows `module.exports = <non-object>` (also accepted as the
+    ///   string option `"allow-primitive-modules"`).
+    /// * `allowRequire`: allows all `require` calls.
+    /// * `allowConditionalRequire`: allows `require` calls inside `if`, `try`, logical and
+    ///   conditional expressions (default `true`).
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// var mod = require('./mod');
+    /// module.exports = { a: 1 };
+    /// exports.b = 2;
+    /// // pass
+    /// import mod from './mod';
+    /// export default { a: 1 };
+    /// export const b = 2;
+    /// ```
+    NoCommonjs,
+    restriction
+);
+
+impl Rule for NoCommonjs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut rule = Self::default();
+        match value.get(0) {
+            Some(serde_json::Value::String(option)) => {
+                rule.allow_primitive_modules = option == "allow-primitive-modules";
+            }
+            Some(config @ serde_json::Value::Object(_)) => {
+                let get_bool = |key: &str| config.get(key).and_then(serde_json::Value::as_bool);
+                if let Some(allow) = get_bool("allowPrimitiveModules") {
+                    rule.allow_primitive_modules = allow;
+                }
+                if let Some(allow) = get_bool("allowRequire") {
+                    rule.allow_require = allow;
+                }
+                if let Some(allow) = get_bool("allowConditionalRequire") {
+                    rule.allow_conditional_require = allow;
+                }
+            }
+            _ => {}
+        }
+        rule
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::MemberExpression(member_expr) => {
+                let Expression::Identifier(object) = member_expr.object() else { return };
+                if !is_global_reference(object, ctx) {
+                    return;
+                }
+                match object.name.as_str() {
+                    "module" if member_expr.static_property_name() == Some("exports") => {
+                        if self.allow_primitive_modules && is_primitive_assignment(node, ctx) {
+                            return;
+                        }
+                        ctx.diagnostic(NoCommonjsDiagnostic::Export(member_expr.span()));
+                    }
+                    "exports" => {
+                        ctx.diagnostic(NoCommonjsDiagnostic::Export(member_expr.span()));
+                    }
+                    _ => {}
+                }
+            }
+            AstKind::CallExpression(call_expr) => {
+                let Expression::Identifier(callee) = &call_expr.callee else { return };
+                if callee.name != "require" || !is_global_reference(callee, ctx) {
+                    return;
+                }
+                if call_expr.arguments.len() != 1 || !is_static_argument(&call_expr.arguments[0]) {
+                    return;
+                }
+                if !is_in_module_scope(node, ctx) || self.allow_require {
+                    return;
+                }
+                if self.allow_conditional_require && is_conditional(node, ctx) {
+                    return;
+                }
+                ctx.diagnostic(NoCommonjsDiagnostic::Import(callee.span));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_static_argument(argument: &Argument) -> bool {
+    match argument {
+        Argument::Expression(Expression::StringLiteral(_)) => true,
+        Argument::Expression(Expression::TemplateLiteral(template)) => {
+            template.expressions.is_empty()
+        }
+        _ => false,
+    }
+}
+
+/// `require` calls are only reported when they are evaluated at module load time,
+/// i.e. the nearest function-like scope is the module scope.
+fn is_in_module_scope(node: &AstNode, ctx: &LintContext) -> bool {
+    let scopes = ctx.scopes();
+    scopes
+        .ancestors(node.scope_id())
+        .find(|scope_id| scopes.get_flags(*scope_id).is_var())
+        .is_some_and(|scope_id| scope_id == scopes.root_scope_id())
+}
+
+fn is_conditional(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes().iter_parents(node.id()).skip(1).any(|parent| {
+        matches!(
+            parent.kind(),
+            AstKind::IfStatement(_)
+                | AstKind::TryStatement(_)
+                | AstKind::LogicalExpression(_)
+                | AstKind::ConditionalExpression(_)
+        )
+    })
+}
+
+/// `module.exports = <value>` where `<value>` is not an object literal.
+fn is_primitive_assignment(node: &AstNode, ctx: &LintContext) -> bool {
+    let Some(AstKind::AssignmentExpression(assign_expr)) =
+        ctx.nodes().iter_parents(node.id()).skip(1).map(AstNode::kind).find(|kind| {
+            !matches!(kind, AstKind::SimpleAssignmentTarget(_) | AstKind::AssignmentTarget(_))
+        })
+    else {
+        return false;
+    };
+    !matches!(assign_expr.right, Expression::ObjectExpression(_))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import 'x';", None),
+        ("import x from 'x'", None),
+        ("import { x } from 'x'", None),
+        ("export default 'x'", None),
+        ("export function house() {}", None),
+        ("function someFunc() { const exports = someComputation(); expect(exports.someProp).toEqual({ a: 'value' }); }", None),
+        // allowed requires
+        ("function a() { var x = require('y'); }", None),
+        ("var a = c && require('b')", None),
+        ("require.resolve('help')", None),
+        ("require.ensure([])", None),
+        ("require([], function(a, b, c) {})", None),
+        ("var bar = require('./bar', true)", None),
+        ("var bar = proxyquire('./bar')", None),
+        ("var bar = require('./ba' + 'r')", None),
+        ("var bar = require(`x${1}`)", None),
+        ("var zero = require(0)", None),
+        ("require('x')", Some(json!([{ "allowRequire": true }]))),
+        ("if (typeof window !== 'undefined') require('x')", None),
+        ("if (typeof window !== 'undefined') require('x')", Some(json!([{ "allowConditionalRequire": true }]))),
+        ("if (typeof window !== 'undefined') { require('x') }", Some(json!([{ "allowRequire": false, "allowConditionalRequire": true }]))),
+        ("try { require('x') } catch (error) {}", None),
+        ("var x = a ? require('x') : undefined", None),
+        // shadowed bindings
+        ("const module = {}; module.exports = {}", None),
+        ("function f(exports) { exports.a = 1 }", None),
+        ("const require = () => {}; require('x')", None),
+        // primitive modules
+        ("module.exports = function () {}", Some(json!(["allow-primitive-modules"]))),
+        ("module.exports = function () {}", Some(json!([{ "allowPrimitiveModules": true }]))),
+        ("module.exports = 'foo'", Some(json!(["allow-primitive-modules"]))),
+    ];
+
+    let fail = vec![
+        ("require('x')", None),
+        ("var x = require('x')", None),
+        ("x = require('x')", None),
+        ("require(`x`)", None),
+        (
+            "if (typeof window !== 'undefined') require('x')",
+            Some(json!([{ "allowConditionalRequire": false }])),
+        ),
+        (
+            "try { require('x') } catch (error) {}",
+            Some(json!([{ "allowConditionalRequire": false }])),
+        ),
+        ("module.exports = {}", None),
+        ("module.exports.face = 'palm'", None),
+        ("exports.face = 'palm'", None),
+        ("module.exports = face", None),
+        ("module.exports = {}", Some(json!(["allow-primitive-modules"]))),
+        ("module.exports.face = 'palm'", Some(json!([{ "allowPrimitiveModules": true }]))),
+        ("module.exports = face", Some(json!([{ "allowPrimitiveModules": false }]))),
+    ];
+
+    Tester::new(NoCommonjs::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,6 +1,5 @@
 use std::{
-    collections::HashSet,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     sync::Arc,
 };
 
@@ -10,7 +9,8 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
-use oxc_syntax::module_record::ModuleRecord;
+use oxc_syntax::module_record::{ModuleRecord, RequestedModule};
+use rustc_hash::FxHashMap;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -21,7 +21,35 @@ struct NoCycleDiagnostic(#[label] Span, String);
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-cycle.md>
 #[derive(Debug, Default, Clone)]
-pub struct NoCycle;
+pub struct NoCycle(Box<NoCycleConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoCycleConfig {
+    /// Maximum number of modules between this module and the one importing it back
+    max_depth: usize,
+    /// Ignore dependencies that are only requested through dynamic `import()`
+    allow_unsafe_dynamic_cyclic_dependency: bool,
+    /// Do not traverse into modules inside `node_modules`
+    ignore_external: bool,
+}
+
+impl Default for NoCycleConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            allow_unsafe_dynamic_cyclic_dependency: false,
+            ignore_external: false,
+        }
+    }
+}
+
+impl std::ops::Deref for NoCycle {
+    type Target = NoCycleConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -37,6 +65,18 @@ declare_oxc_lint!(
     ///
     /// It is common to import an `undefined` value that is caused by a cyclic dependency.
     ///
+    /// ### Options
+    ///
+    /// * `maxDepth`: only report cycles with at most this many modules between this module and
+    ///   the one importing it back, e.g. `1` for direct cycles only. Defaults to `"∞"`.
+    /// * `allowUnsafeDynamicCyclicDependency`: ignore cycles that are only closed by a dynamic
+    ///   `import()`. Dynamic imports are evaluated lazily, but the cycle still exists at runtime.
+    /// * `ignoreExternal`: do not follow dependencies into `node_modules`. External modules
+    ///   are skipped before they count towards `maxDepth`.
+    ///
+    /// Type-only imports are never part of a cycle, both `import type { x }` and imports whose
+    /// specifiers are all inline types such as `import { type x }`.
+    ///
     /// ### Example
     /// ```javascript
     /// // dep-b.js
@@ -53,16 +93,35 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoCycle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        Self(Box::new(NoCycleConfig {
+            max_depth: obj
+                .and_then(|v| v.get("maxDepth"))
+                .and_then(serde_json::Value::as_u64)
+                .map_or(usize::MAX, |n| usize::try_from(n).unwrap_or(usize::MAX)),
+            allow_unsafe_dynamic_cyclic_dependency: obj
+                .and_then(|v| v.get("allowUnsafeDynamicCyclicDependency"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            ignore_external: obj
+                .and_then(|v| v.get("ignoreExternal"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }))
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.semantic().module_record();
 
-        let mut visited: HashSet<PathBuf> = HashSet::new();
+        let mut visited = FxHashMap::default();
         let mut stack: Vec<(Atom, PathBuf)> = Vec::new();
         let cwd = std::env::current_dir().unwrap();
 
         let needle = &module_record.resolved_absolute_path;
-        if visit(module_record, &mut visited, &mut stack, needle) {
-            let span = module_record.requested_modules.get(&stack[0].0).unwrap()[0];
+        if self.visit(module_record, 0, &mut visited, &mut stack, needle) {
+            let requests = module_record.requested_modules.get(&stack[0].0).unwrap();
+            let span = requests.iter().find(|request| self.is_cyclic(request)).unwrap().span();
             let help = stack
                 .into_iter()
                 .map(|(specifier, path)| {
@@ -77,66 +136,173 @@ impl Rule for NoCycle {
     }
 }
 
-/// Walks ModuleRecord and returns the path stack
-/// if there is a cycle
-fn visit(
-    module_record: &Arc<ModuleRecord>,
-    visited: &mut HashSet<PathBuf>,
-    stack: &mut Vec<(Atom, PathBuf)>,
-    needle: &Path,
-) -> bool {
-    let path = &module_record.resolved_absolute_path;
-    if path.components().any(|c| match c {
-        std::path::Component::Normal(p) => p == std::ffi::OsStr::new("node_modules"),
-        _ => false,
-    }) {
-        return false;
-    }
-    for module_record_ref in &module_record.loaded_modules {
-        let resolved_absolute_path = &module_record_ref.resolved_absolute_path;
-        if !visited.insert(resolved_absolute_path.clone()) {
-            continue;
-        }
-        stack.push((module_record_ref.key().clone(), resolved_absolute_path.clone()));
-        if needle == resolved_absolute_path {
-            return true;
-        }
-        if visit(module_record_ref.value(), visited, stack, needle) {
-            return true;
+impl NoCycle {
+    /// Walks ModuleRecord and returns the path stack
+    /// if there is a cycle
+    ///
+    /// `depth` is the number of modules between the linted module and `module_record`.
+    /// `visited` keeps the smallest depth each module was reached at. With a finite `maxDepth`,
+    /// a module first reached through a long path is visited again when a shorter one leaves
+    /// more of `maxDepth`, without one every module is visited once.
+    fn visit(
+        &self,
+        module_record: &Arc<ModuleRecord>,
+        depth: usize,
+        visited: &mut FxHashMap<PathBuf, usize>,
+        stack: &mut Vec<(Atom, PathBuf)>,
+        needle: &Path,
+    ) -> bool {
+        for module_record_ref in &module_record.loaded_modules {
+            if module_record
+                .requested_modules
+                .get(module_record_ref.key())
+                .is_some_and(|requests| !requests.iter().any(|request| self.is_cyclic(request)))
+            {
+                continue;
+            }
+            let resolved_absolute_path = &module_record_ref.resolved_absolute_path;
+            // Pruned before the depth check, external modules never use up `maxDepth`.
+            if self.ignore_external && is_external(resolved_absolute_path) {
+                continue;
+            }
+            if needle == resolved_absolute_path {
+                stack.push((module_record_ref.key().clone(), resolved_absolute_path.clone()));
+                return true;
+            }
+            let next_depth = depth + 1;
+            let unlimited = self.max_depth == usize::MAX;
+            if next_depth > self.max_depth
+                || visited
+                    .get(resolved_absolute_path)
+                    .is_some_and(|d| unlimited || *d <= next_depth)
+            {
+                continue;
+            }
+            visited.insert(resolved_absolute_path.clone(), next_depth);
+            stack.push((module_record_ref.key().clone(), resolved_absolute_path.clone()));
+            if self.visit(module_record_ref.value(), next_depth, visited, stack, needle) {
+                return true;
+            }
+            stack.pop();
         }
-        stack.pop();
+        false
+    }
+
+    /// Whether `request` can be part of a cycle. Type-only imports are erased, and dynamic
+    /// ones may be ignored.
+    fn is_cyclic(&self, request: &RequestedModule) -> bool {
+        !(request.is_type()
+            || (self.allow_unsafe_dynamic_cyclic_dependency && request.is_dynamic()))
     }
-    false
+}
+
+fn is_external(path: &Path) -> bool {
+    path.components().any(|c| matches!(c, Component::Normal(p) if p == "node_modules"))
 }
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
+    use serde_json::json;
+    use std::path::PathBuf;
 
     let pass = vec![
-        "import foo from './foo.js'",
-        "import _ from 'lodash'",
-        "import foo from '@scope/foo'",
-        "require('./foo')",
-        "require('../foo')",
-        "require('foo')",
-        "require('./')",
-        "require('@scope/foo')",
-        "require('./bar/index')",
-        "require('./bar')",
+        ("import foo from './foo.js'", None),
+        ("import _ from 'lodash'", None),
+        ("import foo from '@scope/foo'", None),
+        ("require('./foo')", None),
+        ("require('../foo')", None),
+        ("require('foo')", None),
+        ("require('./')", None),
+        ("require('@scope/foo')", None),
+        ("require('./bar/index')", None),
+        ("require('./bar')", None),
+        (
+            "import { bar } from './es6/depth-one-dynamic'",
+            Some(json!([{ "allowUnsafeDynamicCyclicDependency": true }])),
+        ),
+        (
+            "const bar = () => import('./es6/depth-one')",
+            Some(json!([{ "allowUnsafeDynamicCyclicDependency": true }])),
+        ),
+        ("import { foo } from 'cycles-external'", Some(json!([{ "ignoreExternal": true }]))),
+        ("import { foo } from './es6/depth-two'", Some(json!([{ "maxDepth": 1 }]))),
+        ("import { bar } from './es6/depth-three-indirect'", Some(json!([{ "maxDepth": 2 }]))),
+        (
+            "import 'cycles-external-deep'; import { bar } from './es6/depth-three-indirect'",
+            Some(json!([{ "maxDepth": 2, "ignoreExternal": true }])),
+        ),
+        (
+            "import { foo } from 'cycles-external'",
+            Some(json!([{ "maxDepth": 1, "ignoreExternal": true }])),
+        ),
+        (
+            "import { foo } from 'cycles-external'",
+            Some(json!([{ "maxDepth": "∞", "ignoreExternal": true }])),
+        ),
     ];
 
     let fail = vec![
-        "import { foo } from './es6/depth-one'",
-        "const { foo } = require('./es6/depth-one')",
-        "import { foo } from './es6/depth-one-reexport'",
-        "import { foo } from './es6/depth-two'",
-        "import { foo } from './es6/depth-three-star'",
-        "import { foo } from './es6/depth-three-indirect'",
-        "import { foo } from './intermediate-ignore'",
-        "import { foo } from './ignore'",
+        ("import { foo } from './es6/depth-one'", None),
+        ("const { foo } = require('./es6/depth-one')", None),
+        ("import { foo } from './es6/depth-one-reexport'", None),
+        ("import { foo } from './es6/depth-two'", None),
+        ("import { foo } from './es6/depth-three-star'", None),
+        ("import { foo } from './es6/depth-three-indirect'", None),
+        ("import { foo } from './intermediate-ignore'", None),
+        ("import { foo } from './ignore'", None),
+        ("import { bar } from './es6/depth-one-dynamic'", None),
+        ("const bar = () => import('./es6/depth-one')", None),
+        (
+            "import { foo } from './es6/depth-one'",
+            Some(json!([{ "allowUnsafeDynamicCyclicDependency": true }])),
+        ),
+        (
+            "import { foo } from './es6/depth-one'; const bar = () => import('./es6/depth-one')",
+            Some(json!([{ "allowUnsafeDynamicCyclicDependency": true }])),
+        ),
+        ("import { foo } from 'cycles-external'", None),
+        ("import { foo } from './es6/depth-one'", Some(json!([{ "ignoreExternal": true }]))),
+        ("import { foo } from './es6/depth-one'", Some(json!([{ "maxDepth": 1 }]))),
+        ("import { foo } from './es6/depth-two'", Some(json!([{ "maxDepth": "∞" }]))),
+        ("import { bar } from './es6/depth-three-indirect'", Some(json!([{ "maxDepth": 3 }]))),
+        (
+            "import 'cycles-external-deep'; import { bar } from './es6/depth-three-indirect'",
+            Some(json!([{ "maxDepth": 5, "ignoreExternal": true }])),
+        ),
+        ("import { foo } from 'cycles-external'", Some(json!([{ "maxDepth": 5 }]))),
     ];
 
+    // Type-only imports, linted from `cycles/typescript/depth-zero.ts`
+    let typescript = |source| {
+        TestCase::from((source, None, None, Some(PathBuf::from("cycles/typescript/depth-zero.ts"))))
+    };
+    let pass = pass
+        .into_iter()
+        .map(TestCase::from)
+        .chain(
+            [
+                "import type { foo } from './depth-one'",
+                "import { type foo } from './depth-one'",
+                "import { foo } from './type-only'",
+                "import { foo } from './inline-type-only'",
+            ]
+            .map(typescript),
+        )
+        .collect::<Vec<_>>();
+    let fail = fail
+        .into_iter()
+        .map(TestCase::from)
+        .chain(
+            [
+                "import { foo } from './depth-one'",
+                "import { foo, type foo as bar } from './depth-one'",
+                "import type { foo } from './depth-one'; import { foo as bar } from './depth-one'",
+            ]
+            .map(typescript),
+        )
+        .collect::<Vec<_>>();
+
     Tester::new(NoCycle::NAME, pass, fail)
         .change_rule_path("cycles/depth-zero.js")
         .with_import_plugin(true)
@@ -0,0 +1,277 @@
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-duplicates): '{1}' imported multiple times.")]
+#[diagnostic(severity(warning), help("Merge these imports into a single import statement"))]
+struct NoDuplicatesDiagnostic(#[label] Span, String);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-duplicates.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicates {
+    prefer_inline: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports if the same module is imported more than once in the same file.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Importing a module in several statements is harder to read and maintain than a single
+    /// import listing everything that is used from it.
+    ///
+    /// ### Options
+    ///
+    /// * `prefer-inline`: merge type-only imports into value imports of the same module using
+    ///   inline `type` specifiers (`import { a, type B } from './mod'`). By default
+    ///   `import type` statements are kept separate from value imports.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// import { a } from './mod'
+    /// import { b } from './mod'
+    /// // pass
+    /// import { a, b } from './mod'
+    /// import type { C } from './mod'
+    /// ```
+    NoDuplicates,
+    style
+);
+
+/// Imports of the same module are only reported against imports of the same kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ImportKind {
+    Value,
+    Namespace,
+    DefaultType,
+    NamedType,
+}
+
+impl Rule for NoDuplicates {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let prefer_inline = value
+            .get(0)
+            .and_then(|v| v.get("prefer-inline"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { prefer_inline }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(root) = ctx.nodes().iter().next() else { return };
+        let AstKind::Program(program) = root.kind() else { return };
+
+        let mut groups: FxHashMap<(&str, ImportKind), Vec<&ImportDeclaration>> =
+            FxHashMap::default();
+        for statement in &program.body {
+            let Statement::ModuleDeclaration(decl) = statement else { continue };
+            let ModuleDeclaration::ImportDeclaration(import_decl) = &**decl else { continue };
+            let key = (import_decl.source.value.as_str(), self.import_kind(import_decl));
+            groups.entry(key).or_default().push(import_decl);
+        }
+
+        let mut groups = groups.into_values().filter(|decls| decls.len() > 1).collect::<Vec<_>>();
+        groups.sort_unstable_by_key(|decls| decls[0].span.start);
+
+        for decls in groups {
+            // One fix rewrites the whole group, attach it to the first diagnostic only.
+            let mut fix = self.merge(&decls, ctx);
+            for decl in &decls {
+                let diagnostic =
+                    NoDuplicatesDiagnostic(decl.source.span, decl.source.value.to_string());
+                match fix.take() {
+                    Some(fix) => ctx.diagnostic_with_fix(diagnostic, || fix),
+                    None => ctx.diagnostic(diagnostic),
+                }
+            }
+        }
+    }
+}
+
+impl NoDuplicates {
+    fn import_kind(&self, decl: &ImportDeclaration) -> ImportKind {
+        if !self.prefer_inline && decl.import_kind.is_type() {
+            return if matches!(
+                decl.specifiers.iter().flatten().next(),
+                Some(ImportDeclarationSpecifier::ImportDefaultSpecifier(_))
+            ) {
+                ImportKind::DefaultType
+            } else {
+                ImportKind::NamedType
+            };
+        }
+        if decl.specifiers.iter().flatten().any(|specifier| {
+            matches!(specifier, ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
+        }) {
+            ImportKind::Namespace
+        } else {
+            ImportKind::Value
+        }
+    }
+
+    /// Merges every import of the group into the first one and removes the others.
+    /// Returns `None` when the imports cannot be combined into a single statement.
+    fn merge<'a>(&self, decls: &[&ImportDeclaration], ctx: &LintContext<'a>) -> Option<Fix<'a>> {
+        let source_text = ctx.source_text();
+        let first = decls[0];
+        let last = decls[decls.len() - 1];
+        // Only keep the `import type` form when every import of the group uses it.
+        let keep_top_level_type =
+            !self.prefer_inline && decls.iter().all(|decl| decl.import_kind.is_type());
+
+        let mut default = None;
+        let mut named = vec![];
+        for decl in decls {
+            let inline_type = !keep_top_level_type && decl.import_kind.is_type();
+            for specifier in decl.specifiers.iter().flatten() {
+                let text = specifier.span().source_text(source_text);
+                match specifier {
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return None,
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                        if default.is_some_and(|default| default != text) {
+                            return None;
+                        }
+                        default = Some(text);
+                    }
+                    ImportDeclarationSpecifier::ImportSpecifier(_) => {
+                        let text =
+                            if inline_type { format!("type {text}") } else { text.to_string() };
+                        // `import { a } from 'x'; import { a, b } from 'x'` imports `a` twice
+                        if !named.contains(&text) {
+                            named.push(text);
+                        }
+                    }
+                }
+            }
+        }
+        // A default type import can't be written inline.
+        if default.is_some()
+            && !keep_top_level_type
+            && decls.iter().any(|d| d.import_kind.is_type())
+        {
+            return None;
+        }
+
+        let mut content =
+            String::from(if keep_top_level_type { "import type " } else { "import " });
+        let mut clause = vec![];
+        if let Some(default) = default {
+            clause.push(default.to_string());
+        }
+        if !named.is_empty() {
+            clause.push(format!("{{ {} }}", named.join(", ")));
+        }
+        if !clause.is_empty() {
+            content.push_str(&clause.join(", "));
+            content.push_str(" from ");
+        }
+        content
+            .push_str(Span::new(first.source.span.start, first.span.end).source_text(source_text));
+
+        let mut cursor = first.span.end;
+        for decl in &decls[1..] {
+            content
+                .push_str(Span::new(cursor, decl.span.start).source_text(source_text).trim_end());
+            cursor = decl.span.end;
+        }
+        Some(Fix::new(content, Span::new(first.span.start, last.span.end)))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let prefer_inline = || Some(json!([{ "prefer-inline": true }]));
+
+    let pass = vec![
+        ("import './malformed.js'", None),
+        ("import { x } from './foo'; import { y } from './bar'", None),
+        ("import foo from './foo'; import * as ns from './foo'", None),
+        ("import { x } from './foo'; import type { y } from './foo'", None),
+        ("import type x from './foo'; import type { y } from './foo'", None),
+        ("import * as ns from './foo'; import { type y } from './foo'", prefer_inline()),
+        ("import { x, type y } from './foo'", prefer_inline()),
+    ];
+
+    let fail = vec![
+        ("import './foo'; import './foo'", None),
+        ("import { x } from './foo'; import { y } from './foo'", None),
+        ("import x from './foo'; import { y } from './foo'; import { z } from './foo'", None),
+        ("import * as ns1 from './foo'; import * as ns2 from './foo'", None),
+        ("import type { x } from './foo'; import type { y } from './foo'", None),
+        ("import { x } from './foo'; import type { y } from './foo'", prefer_inline()),
+        ("import { type x } from './foo'; import { type y } from './foo'", None),
+        ("import { x } from './foo'; import { type y } from './foo'", None),
+        ("import { a, type b } from './foo'; import { c } from './foo'", None),
+    ];
+
+    let fix = vec![
+        ("import './foo'; import './foo'", "import './foo';", None),
+        (
+            "import { x } from './foo';\nimport { y } from './foo';",
+            "import { x, y } from './foo';",
+            None,
+        ),
+        (
+            "import x from './foo';\nimport { y } from './foo';\nfoo();\nimport { z } from './foo';",
+            "import x, { y, z } from './foo';\nfoo();",
+            None,
+        ),
+        (
+            "import { a, type b } from './foo';\nimport { c } from './foo';",
+            "import { a, type b, c } from './foo';",
+            None,
+        ),
+        (
+            "import * as ns1 from './foo';\nimport * as ns2 from './foo';",
+            "import * as ns1 from './foo';\nimport * as ns2 from './foo';",
+            None,
+        ),
+        // merging type imports without `prefer-inline` keeps them separate from value imports
+        (
+            "import { x } from './foo';\nimport type { y } from './foo';\nimport type { z } from './foo';",
+            "import { x } from './foo';\nimport type { y, z } from './foo';",
+            None,
+        ),
+        (
+            "import { x } from './foo';\nimport type { y } from './foo';",
+            "import { x, type y } from './foo';",
+            prefer_inline(),
+        ),
+        (
+            "import type { x } from './foo';\nimport { y, type z } from './foo';",
+            "import { type x, y, type z } from './foo';",
+            prefer_inline(),
+        ),
+        // all type imports
+        (
+            "import type { x } from './foo';\nimport type { y as z } from './foo';",
+            "import type { x, y as z } from './foo';",
+            None,
+        ),
+        (
+            "import type { x } from './foo';\nimport type { y as z } from './foo';",
+            "import { type x, type y as z } from './foo';",
+            prefer_inline(),
+        ),
+    ];
+
+    Tester::new(NoDuplicates::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
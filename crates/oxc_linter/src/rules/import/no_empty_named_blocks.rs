@@ -0,0 +1,128 @@
+use oxc_ast::{ast::ImportDeclarationSpecifier, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-empty-named-blocks): Unexpected empty named import block")]
+#[diagnostic(severity(warning), help("Remove the empty `{{}}` or the whole import statement"))]
+struct NoEmptyNamedBlocksDiagnostic(#[label] pub Span);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-empty-named-blocks.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoEmptyNamedBlocks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports the use of empty named import blocks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An empty named import block imports nothing, it is either a leftover from a refactoring
+    /// or should have been written as a side-effect import.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// import {} from 'mod'
+    /// import Default, {} from 'mod'
+    /// // pass
+    /// import { mod } from 'mod'
+    /// import Default, { mod } from 'mod'
+    /// ```
+    NoEmptyNamedBlocks,
+    suspicious
+);
+
+impl Rule for NoEmptyNamedBlocks {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ImportDeclaration(import_decl) = node.kind() else { return };
+        // `import 'mod'`
+        let Some(specifiers) = &import_decl.specifiers else { return };
+
+        let source_start = import_decl.source.span.start;
+        match specifiers.as_slice() {
+            // `import {} from 'mod'`
+            [] => {
+                let Some(span) = find_braces(ctx, import_decl.span.start, source_start) else {
+                    return;
+                };
+                ctx.diagnostic_with_fix(NoEmptyNamedBlocksDiagnostic(span), || {
+                    // `import type {}` imports nothing at runtime, but `import {}` still
+                    // evaluates the module, so keep it as a side-effect import.
+                    if import_decl.import_kind.is_type() {
+                        Fix::delete(import_decl.span)
+                    } else {
+                        Fix::new("import ", Span::new(import_decl.span.start, source_start))
+                    }
+                });
+            }
+            // `import Default, {} from 'mod'`
+            [ImportDeclarationSpecifier::ImportDefaultSpecifier(default_specifier)] => {
+                let default_end = default_specifier.span.end;
+                let Some(span) = find_braces(ctx, default_end, source_start) else { return };
+                ctx.diagnostic_with_fix(NoEmptyNamedBlocksDiagnostic(span), || {
+                    Fix::new(" from ", Span::new(default_end, source_start))
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Finds the `{}` between `start` and `end`.
+#[allow(clippy::cast_possible_truncation)]
+fn find_braces(ctx: &LintContext, start: u32, end: u32) -> Option<Span> {
+    let text = Span::new(start, end).source_text(ctx.source_text());
+    let open = text.find('{')?;
+    let close = text[open..].find('}')? + open;
+    Some(Span::new(start + open as u32, start + close as u32 + 1))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import 'mod'",
+        "import Default from 'mod'",
+        "import { Named } from 'mod'",
+        "import Default, { Named } from 'mod'",
+        "import * as Namespace from 'mod'",
+        "import type Default from 'mod'",
+        "import type { Named } from 'mod'",
+        "import type * as Namespace from 'mod'",
+        "import * as main from './index.js'",
+        "import { a as b } from 'mod'",
+    ];
+
+    let fail = vec![
+        "import {} from 'mod'",
+        "import{}from'mod'",
+        "import type {} from 'mod'",
+        "import type {}from 'mod'",
+        "import Default, {} from 'mod'",
+        "import Default, { } from 'mod'",
+        "import Default,{}from'mod'",
+    ];
+
+    let fix = vec![
+        ("import {} from 'mod'", "import 'mod'", None),
+        ("import{}from'mod'", "import 'mod'", None),
+        ("import {} from 'mod';\nimport a from 'b';", "import 'mod';\nimport a from 'b';", None),
+        ("import type {} from 'mod'", "", None),
+        ("import type {} from 'mod';\nimport a from 'b';", "\nimport a from 'b';", None),
+        ("import Default, {} from 'mod'", "import Default from 'mod'", None),
+        ("import Default, { } from 'mod';", "import Default from 'mod';", None),
+        ("import Default,{}from'mod'", "import Default from 'mod'", None),
+    ];
+
+    Tester::new(NoEmptyNamedBlocks::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
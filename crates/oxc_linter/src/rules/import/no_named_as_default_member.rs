@@ -170,6 +170,8 @@ fn test() {
             const baz = {};
             const a = baz.looooooooooooooooooooooooong;
         }",
+        r#"import foo from "./mixed-exports"; const x = foo.somethingElse;"#,
+        r#"import foo from "./mixed-exports"; foo.somethingElse();"#,
     ];
 
     let fail = vec![
@@ -182,6 +184,8 @@ fn test() {
         }",
         r#"import baz, { bar } from "./named-exports"; const {a} = baz"#,
         r#"import baz from "./named-and-default-export"; const {foo: _foo} = baz"#,
+        r#"import foo from "./mixed-exports"; const x = foo.bar;"#,
+        r#"import foo from "./mixed-exports"; foo.bar();"#,
     ];
 
     Tester::new(NoNamedAsDefaultMember::NAME, pass, fail)
@@ -0,0 +1,155 @@
+use oxc_ast::{
+    ast::{Argument, Expression, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::is_global_require_call, context::LintContext, rule::Rule, utils::is_builtin_module,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-nodejs-modules): Do not import Node.js builtin module \"{1}\"")]
+#[diagnostic(
+    severity(warning),
+    help("Use a browser-compatible package, or add the module to the `allow` list")
+)]
+struct NoNodejsModulesDiagnostic(#[label] pub Span, String);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-nodejs-modules.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoNodejsModules(Box<NoNodejsModulesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNodejsModulesConfig {
+    allow: Vec<String>,
+}
+
+impl std::ops::Deref for NoNodejsModules {
+    type Target = NoNodejsModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids the use of Node.js builtin modules, with or without the `node:` prefix.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Code that runs in the browser can't load modules such as `fs` or `path`; importing
+    /// them either breaks at runtime or pulls large polyfills into the bundle.
+    ///
+    /// ### Options
+    ///
+    /// * `allow`: builtin modules that may still be imported, as written in the source,
+    ///   e.g. `["path", "node:events"]`.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// import fs from 'fs'
+    /// const path = require('node:path')
+    /// // pass
+    /// import _ from 'lodash'
+    /// import path from 'path' // with `{ "allow": ["path"] }`
+    /// ```
+    NoNodejsModules,
+    restriction
+);
+
+impl Rule for NoNodejsModules {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow = value
+            .get(0)
+            .and_then(|v| v.get("allow"))
+            .and_then(serde_json::Value::as_array)
+            .map(|allow| allow.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+        Self(Box::new(NoNodejsModulesConfig { allow }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let source = match node.kind() {
+            AstKind::ImportDeclaration(decl) => &decl.source,
+            AstKind::ExportNamedDeclaration(decl) => {
+                let Some(source) = &decl.source else { return };
+                source
+            }
+            AstKind::ExportAllDeclaration(decl) => &decl.source,
+            AstKind::CallExpression(call_expr) if is_global_require_call(call_expr, ctx) => {
+                let Argument::Expression(Expression::StringLiteral(source)) =
+                    &call_expr.arguments[0]
+                else {
+                    return;
+                };
+                source
+            }
+            _ => return,
+        };
+        self.check(source, ctx);
+    }
+}
+
+impl NoNodejsModules {
+    fn check(&self, source: &StringLiteral, ctx: &LintContext) {
+        let name = source.value.as_str();
+        if !is_builtin_module(name) || self.allow.iter().any(|allowed| allowed == name) {
+            return;
+        }
+        ctx.diagnostic(NoNodejsModulesDiagnostic(source.span, name.to_string()));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import _ from 'lodash'", None),
+        ("import find from 'lodash.find'", None),
+        ("import foo from './foo'", None),
+        ("import foo from '../foo'", None),
+        ("import foo from 'foo'", None),
+        ("import foo from './'", None),
+        ("import foo from '@scope/foo'", None),
+        ("var _ = require('lodash')", None),
+        ("var find = require('lodash.find')", None),
+        ("var foo = require('./foo')", None),
+        ("var foo = require('foo')", None),
+        ("var foo = require('@scope/foo')", None),
+        ("import events from 'events'", Some(json!([{ "allow": ["events"] }]))),
+        ("import path from 'path'", Some(json!([{ "allow": ["path"] }]))),
+        ("var path = require('path')", Some(json!([{ "allow": ["path"] }]))),
+        ("import path from 'node:path'", Some(json!([{ "allow": ["node:path"] }]))),
+        ("var fs = require(fsName)", None),
+        ("function require() {}; require('fs')", None),
+    ];
+
+    let fail = vec![
+        ("import path from 'path'", None),
+        ("import fs from 'fs'", None),
+        ("import path from 'node:path'", None),
+        ("import { test } from 'node:test'", None),
+        ("import { readFile } from 'fs/promises'", None),
+        ("var path = require('path')", None),
+        ("var fs = require('node:fs')", None),
+        ("export { join } from 'path'", None),
+        ("export * from 'fs'", None),
+        ("import fs from 'fs'", Some(json!([{ "allow": ["path"] }]))),
+        ("import path from 'node:path'", Some(json!([{ "allow": ["path"] }]))),
+    ];
+
+    Tester::new(NoNodejsModules::NAME, pass, fail).test_and_snapshot();
+}
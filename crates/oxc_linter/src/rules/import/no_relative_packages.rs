@@ -0,0 +1,164 @@
+use std::path::{Path, PathBuf};
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, utils::normalize_path};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-relative-packages): Relative import from another package is not allowed.")]
+#[diagnostic(severity(warning), help("Use `{1}` instead of `{2}`"))]
+struct NoRelativePackagesDiagnostic(#[label] Span, String, String);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-relative-packages.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoRelativePackages;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports relative imports that reach into another package, i.e. a directory with its own
+    /// `package.json`, and suggests importing it by its package name instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In a monorepo, a relative path into a sibling package bypasses its `package.json`
+    /// (`main`, `exports`, dependencies) and breaks as soon as the packages are published or
+    /// moved independently.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // packages/a/index.js, where packages/b/package.json is named "b"
+    /// // fail
+    /// import foo from '../b/src/foo'
+    /// // pass
+    /// import foo from 'b/src/foo'
+    /// import bar from './bar'
+    /// ```
+    NoRelativePackages,
+    restriction
+);
+
+impl Rule for NoRelativePackages {
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.semantic().module_record();
+        let dir = ctx.file_path().parent().unwrap_or_else(|| Path::new(""));
+        let Some(context_root) = find_package_root(dir) else { return };
+
+        for (request, requested_modules) in &module_record.requested_modules {
+            let request = request.as_str();
+            if !matches!(request, "." | "..")
+                && !request.starts_with("./")
+                && !request.starts_with("../")
+            {
+                continue;
+            }
+            let target = normalize_path(&dir.join(request));
+            // A directory may be a package itself, a file belongs to the package above it.
+            let start = if target.is_dir() { Some(target.as_path()) } else { target.parent() };
+            let Some(import_root) = start.and_then(find_package_root) else { continue };
+            if import_root == context_root {
+                continue;
+            }
+            let Some(name) = read_package_name(&import_root) else { continue };
+            let Ok(subpath) = target.strip_prefix(&import_root) else { continue };
+            let proper_import = if subpath.as_os_str().is_empty() {
+                name
+            } else {
+                format!("{name}/{}", subpath.to_string_lossy().replace('\\', "/"))
+            };
+
+            for requested_module in requested_modules {
+                let span = requested_module.span();
+                ctx.diagnostic_with_fix(
+                    NoRelativePackagesDiagnostic(span, proper_import.clone(), request.to_string()),
+                    || {
+                        let quote = &span.source_text(ctx.source_text())[..1];
+                        Fix::new(format!("{quote}{proper_import}{quote}"), span)
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the closest directory at or above `dir` that contains a `package.json`.
+fn find_package_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors().find(|dir| dir.join("package.json").is_file()).map(Path::to_path_buf)
+}
+
+fn read_package_name(package_root: &Path) -> Option<String> {
+    let package_json = std::fs::read_to_string(package_root.join("package.json")).ok()?;
+    let package_json: serde_json::Value = serde_json::from_str(&package_json).ok()?;
+    package_json.get("name")?.as_str().map(String::from)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    {
+        let pass = vec![
+            "import { foo } from 'a'",
+            "import { foo } from 'package-named'",
+            "import foo from './index.js'",
+            "import bar from '../bar'",
+            "import foo from '../package'",
+            "const foo = import(foo)",
+        ];
+
+        let fail = vec![
+            "import foo from '../package-named'",
+            "import foo from '../package-named/index.js'",
+            "export * from '../package-named'",
+            "const foo = require('../package-named')",
+            "const foo = import('../package-named')",
+        ];
+
+        let fix = vec![
+            ("import foo from '../package-named'", "import foo from 'package-named'", None),
+            (
+                "import foo from \"../package-named/index.js\"",
+                "import foo from \"package-named/index.js\"",
+                None,
+            ),
+            ("export { foo } from '../package-named'", "export { foo } from 'package-named'", None),
+        ];
+
+        Tester::new(NoRelativePackages::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("package-scoped/index.js")
+            .expect_fix(fix)
+            .test_and_snapshot();
+    }
+
+    {
+        let pass = vec!["import foo from './index.js'", "import bar from '../bar'"];
+        let fail = vec![];
+
+        Tester::new(NoRelativePackages::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("package/index.js")
+            .test();
+    }
+
+    {
+        let pass = vec!["import bar from './bar'"];
+        let fail = vec!["import foo from './package-named'", "import foo from './package-scoped'"];
+        let fix = vec![
+            ("import foo from './package-named'", "import foo from 'package-named'", None),
+            ("import foo from './package-scoped'", "import foo from '@scope/package-named'", None),
+        ];
+
+        Tester::new(NoRelativePackages::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("bar.js")
+            .expect_fix(fix)
+            .test();
+    }
+}
@@ -0,0 +1,265 @@
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobMatcher};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{canonicalize_path, normalize_path},
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-restricted-paths): Unexpected path \"{1}\" imported in restricted zone.{2}")]
+#[diagnostic(severity(warning))]
+struct NoRestrictedPathsDiagnostic(#[label] Span, String, String);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-restricted-paths.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedPaths(Box<NoRestrictedPathsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedPathsConfig {
+    zones: Vec<Zone>,
+}
+
+impl std::ops::Deref for NoRestrictedPaths {
+    type Target = NoRestrictedPathsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Zone {
+    target: Vec<PathPattern>,
+    from: Vec<PathPattern>,
+    except: Vec<PathPattern>,
+    message: Option<String>,
+}
+
+/// A directory (matching everything inside it) or a glob, both absolute and canonical like
+/// resolved module paths.
+#[derive(Debug, Clone)]
+enum PathPattern {
+    Dir(PathBuf),
+    Glob(GlobMatcher),
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids files in one part of the project from importing files in another, to enforce
+    /// architectural boundaries.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some code must never depend on other code, e.g. client code bundled for the browser
+    /// must not pull in server code. Without a check these boundaries erode one import at a
+    /// time.
+    ///
+    /// ### Options
+    ///
+    /// * `zones`: a list of `{ target, from, except, message }` objects. Files matched by
+    ///   `target` may not import modules matched by `from`, unless the imported module is
+    ///   matched by `except`. `target` and `from` are a path or a list of paths, either
+    ///   directories or glob patterns. `except` paths are relative to `from` when `from` is a
+    ///   directory. `message` is appended to the diagnostic.
+    /// * `basePath`: the directory relative paths are resolved against, defaults to the
+    ///   current working directory.
+    ///
+    /// Imports are resolved with the import plugin's resolver; unresolved imports are ignored.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // { "zones": [{ "target": "./client", "from": "./server" }] }
+    /// // client/a.js
+    /// // fail
+    /// import b from '../server/b'
+    /// // pass
+    /// import a from './a'
+    /// ```
+    NoRestrictedPaths,
+    restriction
+);
+
+impl Rule for NoRestrictedPaths {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        let base_path = config
+            .get("basePath")
+            .and_then(serde_json::Value::as_str)
+            .map_or_else(|| std::env::current_dir().unwrap_or_default(), PathBuf::from);
+        let base_path = canonicalize_path(&base_path);
+        let zones = config
+            .get("zones")
+            .and_then(serde_json::Value::as_array)
+            .map(|zones| zones.iter().map(|zone| Zone::new(zone, &base_path)).collect())
+            .unwrap_or_default();
+        Self(Box::new(NoRestrictedPathsConfig { zones }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let file_path = canonicalize_path(ctx.file_path());
+        let zones =
+            self.zones.iter().filter(|zone| zone.target.iter().any(|t| t.matches(&file_path)));
+        let module_record = ctx.semantic().module_record();
+        for zone in zones {
+            for (request, requested_modules) in &module_record.requested_modules {
+                let Some(remote_module_record) = module_record.loaded_modules.get(request) else {
+                    continue;
+                };
+                // Resolved paths are relative too when the linted path is.
+                let import_path = canonicalize_path(&remote_module_record.resolved_absolute_path);
+                if !zone.from.iter().any(|from| from.matches(&import_path))
+                    || zone.except.iter().any(|except| except.matches(&import_path))
+                {
+                    continue;
+                }
+                let message =
+                    zone.message.as_ref().map(|message| format!(" {message}")).unwrap_or_default();
+                for requested_module in requested_modules {
+                    ctx.diagnostic(NoRestrictedPathsDiagnostic(
+                        requested_module.span(),
+                        request.to_string(),
+                        message.clone(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+impl Zone {
+    fn new(zone: &serde_json::Value, base_path: &Path) -> Self {
+        let target = PathPattern::from_value(zone.get("target"), base_path);
+        let from = PathPattern::from_value(zone.get("from"), base_path);
+        // `except` is relative to `from` when it names a single directory.
+        let except_base = match from.as_slice() {
+            [PathPattern::Dir(dir)] => dir.as_path(),
+            _ => base_path,
+        };
+        let except = PathPattern::from_value(zone.get("except"), except_base);
+        let message = zone.get("message").and_then(serde_json::Value::as_str).map(String::from);
+        Self { target, from, except, message }
+    }
+}
+
+impl PathPattern {
+    fn from_value(value: Option<&serde_json::Value>, base_path: &Path) -> Vec<Self> {
+        let paths = match value {
+            Some(serde_json::Value::String(path)) => vec![path.as_str()],
+            Some(serde_json::Value::Array(paths)) => {
+                paths.iter().filter_map(serde_json::Value::as_str).collect()
+            }
+            _ => vec![],
+        };
+        paths.into_iter().filter_map(|path| Self::new(path, base_path)).collect()
+    }
+
+    fn new(path: &str, base_path: &Path) -> Option<Self> {
+        let path = base_path.join(path);
+        let path_str = path.to_string_lossy();
+        if path_str.contains(['*', '?', '[', '{']) {
+            let path = normalize_path(&path);
+            Glob::new(&path.to_string_lossy()).ok().map(|glob| Self::Glob(glob.compile_matcher()))
+        } else {
+            Some(Self::Dir(canonicalize_path(&path)))
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        match self {
+            Self::Dir(dir) => path.starts_with(dir),
+            Self::Glob(glob) => glob.is_match(path),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    fn zone(target: &str, from: &str) -> serde_json::Value {
+        let base = "./fixtures/import/restricted-paths";
+        json!({ "target": format!("{base}/{target}"), "from": format!("{base}/{from}") })
+    }
+    let zones = |zones: &[serde_json::Value]| Some(json!([{ "zones": zones }]));
+
+    {
+        let pass = vec![
+            ("import a from '../client/a.js'", zones(&[zone("server", "other")])),
+            ("const a = require('../client/a.js')", zones(&[zone("server", "other")])),
+            ("import b from './b.js'", zones(&[zone("server/**/*", "client/**/*")])),
+            ("import a from '../client/a.js'", zones(&[zone("client/**/*", "server/**/*")])),
+            ("import b from './one/b.js'", zones(&[zone("server/two", "server/one")])),
+            ("import a from 'does-not-exist'", zones(&[zone("server", "client")])),
+            ("import a from '../client/a.js'", None),
+        ];
+
+        let mut with_message = zone("server", "client");
+        with_message["message"] = json!("Server code must not depend on client code.");
+        let fail = vec![
+            ("import a from '../client/a.js'", zones(&[zone("server", "client")])),
+            ("import a from '../client/a'", zones(&[zone("server/**/*", "client/**/*")])),
+            ("import a from '../client/one/a.js'", zones(&[zone("server", "client/one")])),
+            ("const a = require('../client/a.js')", zones(&[zone("server", "client")])),
+            ("export { a } from '../client/a.js'", zones(&[zone("server", "client")])),
+            (
+                "import a from '../client/a.js'",
+                zones(&[zone("server/one", "client"), zone("server", "client")]),
+            ),
+            ("import a from '../client/a.js'", zones(&[with_message])),
+        ];
+
+        Tester::new(NoRestrictedPaths::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("restricted-paths/server/b.js")
+            .test_and_snapshot();
+    }
+
+    {
+        // `client/**` may not import `server/**`, except `server/one`
+        let mut zone = zone("client/**/*", "server");
+        zone["except"] = json!(["./one"]);
+        let zones = || zones(&[zone.clone()]);
+
+        let pass = vec![
+            ("import a from './one/a.js'", zones()),
+            ("import a from '../server/one/a.js'", zones()),
+            ("import b from '../server/one/b.js'", zones()),
+        ];
+
+        let fail = vec![
+            ("import b from '../server/b.js'", zones()),
+            ("import a from '../server/two/a.js'", zones()),
+            ("import a from '../server/two-new/a.js'", zones()),
+            ("const a = import('../server/three/a.js')", zones()),
+        ];
+
+        Tester::new(NoRestrictedPaths::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("restricted-paths/client/a.js")
+            .test();
+    }
+
+    {
+        // Linted through a relative path, like `oxlint src`
+        let pass = vec![("import a from './one/a.js'", zones(&[zone("client", "server")]))];
+        let fail = vec![("import b from '../server/b.js'", zones(&[zone("client", "server")]))];
+
+        Tester::new(NoRestrictedPaths::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("restricted-paths/client/a.js")
+            .with_relative_rule_path(true)
+            .test();
+    }
+}
@@ -0,0 +1,172 @@
+use std::path::Path;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::is_global_require_call, context::LintContext, rule::Rule, utils::normalize_path,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-unassigned-import): Imported module should be assigned")]
+#[diagnostic(
+    severity(warning),
+    help("Bind the import to a name, or add the module to the `allow` list if it is imported for its side effects")
+)]
+struct NoUnassignedImportDiagnostic(#[label] pub Span);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unassigned-import.md>
+#[derive(Debug, Clone)]
+pub struct NoUnassignedImport(Box<GlobSet>);
+
+impl Default for NoUnassignedImport {
+    fn default() -> Self {
+        Self(Box::new(GlobSet::empty()))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports imports and `require` calls whose result is not assigned, i.e. modules that are
+    /// only loaded for their side effects.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Side-effect imports make it hard to tell what a module depends on and why. They are
+    /// usually intended only for a few kinds of modules such as stylesheets or polyfills.
+    ///
+    /// ### Options
+    ///
+    /// * `allow`: a list of glob patterns for modules that may be imported without assignment,
+    ///   e.g. `["**/*.css", "babel-register"]`. Relative paths are matched after being resolved
+    ///   against the directory of the linted file.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// import 'should'
+    /// require('should')
+    /// // pass
+    /// import _ from 'foo'
+    /// const _ = require('foo')
+    /// import './styles.css' // with `{ "allow": ["**/*.css"] }`
+    /// ```
+    NoUnassignedImport,
+    restriction
+);
+
+impl Rule for NoUnassignedImport {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        if let Some(globs) = value.get(0).and_then(|v| v.get("allow")).and_then(|v| v.as_array()) {
+            for glob in globs.iter().filter_map(serde_json::Value::as_str) {
+                if let Ok(glob) = Glob::new(glob) {
+                    builder.add(glob);
+                }
+            }
+        }
+        Self(Box::new(builder.build().unwrap_or_else(|_| GlobSet::empty())))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ImportDeclaration(import_decl) => {
+                if import_decl.specifiers.as_ref().is_some_and(|specifiers| !specifiers.is_empty())
+                {
+                    return;
+                }
+                if self.is_allowed(import_decl.source.value.as_str(), ctx) {
+                    return;
+                }
+                ctx.diagnostic(NoUnassignedImportDiagnostic(import_decl.span));
+            }
+            AstKind::ExpressionStatement(stmt) => {
+                let Expression::CallExpression(call_expr) = &stmt.expression else { return };
+                if !is_global_require_call(call_expr, ctx) {
+                    return;
+                }
+                let Argument::Expression(Expression::StringLiteral(source)) =
+                    &call_expr.arguments[0]
+                else {
+                    return;
+                };
+                if self.is_allowed(source.value.as_str(), ctx) {
+                    return;
+                }
+                ctx.diagnostic(NoUnassignedImportDiagnostic(call_expr.span));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoUnassignedImport {
+    fn is_allowed(&self, source: &str, ctx: &LintContext) -> bool {
+        if self.0.is_empty() {
+            return false;
+        }
+        if !source.starts_with('.') && !source.starts_with('/') {
+            return self.0.is_match(source);
+        }
+        let dir = ctx.file_path().parent().unwrap_or_else(|| Path::new(""));
+        self.0.is_match(normalize_path(&dir.join(source)))
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import _ from 'lodash'", None),
+        ("import _, {foo} from 'lodash'", None),
+        ("import _, {foo as bar} from 'lodash'", None),
+        ("import {foo as bar} from 'lodash'", None),
+        ("import * as _ from 'lodash'", None),
+        ("import _ from './'", None),
+        ("const _ = require('lodash')", None),
+        ("const {foo} = require('lodash')", None),
+        ("const {foo: bar} = require('lodash')", None),
+        ("const [a, b] = require('lodash')", None),
+        ("const _ = require('./')", None),
+        ("foo(require('lodash'))", None),
+        ("require('lodash').foo", None),
+        ("require('lodash').foo()", None),
+        ("require('lodash')()", None),
+        ("require(foo)", None),
+        ("function require() {}; require('lodash')", None),
+        ("import 'app.css'", Some(json!([{ "allow": ["**/*.css"] }]))),
+        ("import './styles.css'", Some(json!([{ "allow": ["**/*.css"] }]))),
+        ("import '../styles/app.css'", Some(json!([{ "allow": ["**/styles/*.css"] }]))),
+        ("import 'babel-register'", Some(json!([{ "allow": ["babel-register"] }]))),
+        ("require('./styles.css')", Some(json!([{ "allow": ["**/*.css"] }]))),
+        ("import 'foo/bar'", Some(json!([{ "allow": ["foo/**"] }]))),
+        ("import 'foo/bar'", Some(json!([{ "allow": ["foo/bar"] }]))),
+    ];
+
+    let fail = vec![
+        ("require('should')", None),
+        ("import 'should'", None),
+        ("import {} from 'should'", None),
+        ("import './styles.css'", None),
+        ("import './styles.scss'", Some(json!([{ "allow": ["**/*.css"] }]))),
+        ("import 'foo'", Some(json!([{ "allow": ["bar"] }]))),
+        ("require('foo')", Some(json!([{ "allow": ["**/*.css"] }]))),
+        ("import '../styles/app.css'", Some(json!([{ "allow": ["**/vendor/*.css"] }]))),
+    ];
+
+    Tester::new(NoUnassignedImport::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,168 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, utils::is_builtin_module};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-unresolved): Unable to resolve path to module \"{1}\"")]
+#[diagnostic(
+    severity(warning),
+    help("Check the path, or configure `settings[\"import/resolver\"]` if it is resolved at build time")
+)]
+struct NoUnresolvedDiagnostic(#[label] Span, String);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unresolved.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolvedConfig {
+    ignore: Vec<Regex>,
+}
+
+impl std::ops::Deref for NoUnresolved {
+    type Target = NoUnresolvedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures an imported module can be resolved to a file on disk. Static and dynamic
+    /// imports, re-exports and top-level `require` declarations are checked.
+    ///
+    /// Modules are resolved like Node.js does, configured by `settings["import/resolver"]`
+    /// or `settings["import/resolver"].node`, other resolvers are ignored:
+    ///
+    /// * `extensions`: extensions tried for extensionless specifiers, defaults to every
+    ///   extension the parser supports.
+    /// * `alias`: specifiers mapped to paths, e.g. `{ "@": "./src" }`.
+    /// * `paths`: directories searched for bare specifiers after `node_modules`.
+    ///
+    /// The same resolver builds the module graph used by the other import rules.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An import of a module that doesn't exist, e.g. because of a typo or a moved file,
+    /// fails at runtime or at build time.
+    ///
+    /// ### Options
+    ///
+    /// * `ignore`: regular expressions matching specifiers that are not checked.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// import foo from './does-not-exist'
+    /// // pass
+    /// import foo from './foo'
+    /// import fs from 'fs'
+    /// ```
+    NoUnresolved,
+    nursery
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore = value
+            .get(0)
+            .and_then(|v| v.get("ignore"))
+            .and_then(serde_json::Value::as_array)
+            .map(|ignore| {
+                ignore.iter().filter_map(|v| v.as_str().and_then(|s| Regex::new(s).ok())).collect()
+            })
+            .unwrap_or_default();
+        Self(Box::new(NoUnresolvedConfig { ignore }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(resolver) = ctx.resolver() else { return };
+        let Some(dir) = ctx.file_path().parent() else { return };
+        let module_record = ctx.semantic().module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            if is_builtin_module(specifier)
+                || self.ignore.iter().any(|ignore| ignore.is_match(specifier))
+                || resolver.resolve(dir, specifier).is_ok()
+            {
+                continue;
+            }
+            for requested_module in requested_modules {
+                ctx.diagnostic(NoUnresolvedDiagnostic(
+                    requested_module.span(),
+                    specifier.to_string(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let resolver = |settings: serde_json::Value| Some(json!({ "import/resolver": settings }));
+
+    let pass = vec![
+        ("import foo from './bar'", None, None),
+        ("import { foo } from './bar.js'", None, None),
+        ("import Component from './jsx/MyCoolComponent'", None, None),
+        ("import fs from 'fs'", None, None),
+        ("import fs from 'node:fs'", None, None),
+        ("export * from './bar'", None, None),
+        ("export { foo } from './bar'", None, None),
+        ("import('./bar')", None, None),
+        ("function foo() { require('./does-not-exist') }", None, None),
+        (
+            "import foo from './does-not-exist'",
+            Some(json!([{ "ignore": ["^\\./does-not"] }])),
+            None,
+        ),
+        ("import foo from './test'", None, resolver(json!({ "extensions": [".coffee"] }))),
+        (
+            "import Component from '~/MyCoolComponent'",
+            None,
+            resolver(json!({ "alias": { "~": "./jsx" } })),
+        ),
+        ("import Component from 'MyCoolComponent'", None, resolver(json!({ "paths": ["./jsx"] }))),
+        (
+            "import foo from './test'",
+            None,
+            resolver(json!({ "node": { "extensions": [".coffee"] }, "webpack": {} })),
+        ),
+        ("import foo from './bar'", None, resolver(json!("node"))),
+    ];
+
+    let fail = vec![
+        ("import foo from './does-not-exist'", None, None),
+        ("import foo from 'does-not-exist'", None, None),
+        ("import foo from './test'", None, None),
+        ("export { foo } from './does-not-exist'", None, None),
+        ("export * from './does-not-exist'", None, None),
+        ("import('./does-not-exist')", None, None),
+        ("const foo = require('./does-not-exist')", None, None),
+        ("import foo from './bar'; import foo2 from './bar'; import baz from './baz'", None, None),
+        ("import foo from './does-not-exist'", Some(json!([{ "ignore": ["^\\./bar"] }])), None),
+        (
+            "import Component from './jsx/MyCoolComponent'",
+            None,
+            resolver(json!({ "extensions": [".js"] })),
+        ),
+        ("import Component from '~/MyCoolComponent'", None, None),
+        ("import Component from 'MyCoolComponent'", None, None),
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail)
+        .with_import_plugin(true)
+        .change_rule_path("index.js")
+        .test_and_snapshot();
+}
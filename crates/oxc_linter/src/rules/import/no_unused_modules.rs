@@ -0,0 +1,304 @@
+use std::path::PathBuf;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::canonicalize_path};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoUnusedModulesDiagnostic {
+    #[error("eslint-plugin-import(no-unused-modules): No exports found")]
+    #[diagnostic(severity(warning))]
+    MissingExports(#[label] Span),
+    #[error("eslint-plugin-import(no-unused-modules): Exported declaration \"{1}\" is not used within other modules")]
+    #[diagnostic(severity(warning))]
+    UnusedExport(#[label] Span, String),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unused-modules.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedModules(Box<NoUnusedModulesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedModulesConfig {
+    missing_exports: bool,
+    unused_exports: bool,
+    /// Files and directories whose imports count as uses, all absolute and canonical.
+    src: Vec<PathBuf>,
+    ignore_exports: GlobSet,
+}
+
+impl std::ops::Deref for NoUnusedModules {
+    type Target = NoUnusedModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports modules without any exports, and exports that are not imported by any other
+    /// module of the project.
+    ///
+    /// The project's imports are collected from the `src` files and resolved with the
+    /// import plugin's resolver. Namespace imports, `export * from` and dynamic `import()`
+    /// count as using every export of the imported module. Type-only imports, `import type { x }`
+    /// and `import { type x }`, count as uses too.
+    ///
+    /// The module records of the `src` files are loaded into the lint run's module graph
+    /// before any file is linted, so that every file is checked against all of them.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Exports that nothing imports are dead code, which is easy to miss because every
+    /// export looks used from within its own module.
+    ///
+    /// ### Options
+    ///
+    /// * `missingExports`: report modules without any exports.
+    /// * `unusedExports`: report exports not imported by any `src` file.
+    /// * `src`: files and directories, relative to the current working directory, whose
+    ///   imports are collected. Defaults to the current working directory.
+    /// * `ignoreExports`: globs of files whose exports are not reported, e.g. entry points.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // { "unusedExports": true }
+    /// // a.js
+    /// import { used } from './b'
+    /// // b.js
+    /// export const used = 1
+    /// export const unused = 2 // fail
+    /// ```
+    NoUnusedModules,
+    nursery
+);
+
+impl Rule for NoUnusedModules {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        let cwd = canonicalize_path(&std::env::current_dir().unwrap_or_default());
+        let flag = |key| config.get(key).and_then(serde_json::Value::as_bool).unwrap_or_default();
+        let paths = |key| {
+            config
+                .get(key)
+                .and_then(serde_json::Value::as_array)
+                .map(|paths| {
+                    paths
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(|path| canonicalize_path(&cwd.join(path)))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+        let src = paths("src");
+        let mut ignore_exports = GlobSetBuilder::new();
+        for path in paths("ignoreExports") {
+            if let Ok(glob) = Glob::new(&path.to_string_lossy()) {
+                ignore_exports.add(glob);
+            }
+        }
+        Self(Box::new(NoUnusedModulesConfig {
+            missing_exports: flag("missingExports"),
+            unused_exports: flag("unusedExports"),
+            src: if src.is_empty() { vec![cwd] } else { src },
+            ignore_exports: ignore_exports.build().unwrap_or_else(|_| GlobSet::empty()),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        // Usages are keyed by resolved paths and `ignoreExports` is absolute, while the
+        // linted path is relative when the CLI is given relative paths.
+        let file_path = canonicalize_path(ctx.file_path());
+        if self.ignore_exports.is_match(&file_path) {
+            return;
+        }
+        let module_record = ctx.semantic().module_record();
+
+        if self.missing_exports
+            && module_record.exported_bindings.is_empty()
+            && module_record.export_default.is_none()
+            && module_record.star_export_entries.is_empty()
+        {
+            ctx.diagnostic(NoUnusedModulesDiagnostic::MissingExports(Span::new(0, 0)));
+        }
+
+        if !self.unused_exports {
+            return;
+        }
+        let Some(usages) = ctx.export_usages() else { return };
+        let usage = usages.get(&file_path);
+        let is_used = |name: &str| usage.is_some_and(|usage| usage.is_used(name));
+
+        let mut unused = module_record
+            .exported_bindings
+            .iter()
+            .map(|(name, span)| (name.as_str(), *span))
+            .chain(module_record.export_default.map(|span| ("default", span)))
+            .filter(|(name, _)| !is_used(name))
+            .collect::<Vec<_>>();
+        unused.sort_unstable_by_key(|(_, span)| span.start);
+        for (name, span) in unused {
+            ctx.diagnostic(NoUnusedModulesDiagnostic::UnusedExport(span, name.to_string()));
+        }
+    }
+}
+
+impl NoUnusedModules {
+    /// The files and directories whose imports are collected, when unused exports are reported.
+    pub fn src(&self) -> Option<&[PathBuf]> {
+        self.unused_exports.then_some(self.src.as_slice())
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let src = json!(["./fixtures/import/no-unused-modules"]);
+    let unused_exports = || Some(json!([{ "unusedExports": true, "src": src }]));
+
+    {
+        // `file-0.js` imports `b` from `file-b.js`
+        let pass = vec![
+            ("export const b = 2", unused_exports()),
+            ("const b = 2; export { b }", unused_exports()),
+            ("const a = 1; export { a as b }", unused_exports()),
+            ("export const c = 2", None),
+            (
+                "export const c = 2",
+                Some(json!([{
+                    "unusedExports": true,
+                    "src": src,
+                    "ignoreExports": ["./fixtures/import/no-unused-modules/file-b.js"]
+                }])),
+            ),
+        ];
+
+        let fail = vec![
+            ("export const c = 2", unused_exports()),
+            ("export const b = 2; export const c = 3", unused_exports()),
+            ("const b = 2; export { b as c }", unused_exports()),
+            ("export default 2", unused_exports()),
+            ("export const b = 2", Some(json!([{ "unusedExports": true, "src": ["./src"] }]))),
+        ];
+
+        Tester::new(NoUnusedModules::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-unused-modules/file-b.js")
+            .test_and_snapshot();
+    }
+
+    {
+        // `file-a.js` imports `o2`, `file-0.js` re-exports `default`, `o0` and `o3`
+        let pass = vec![
+            ("export const o0 = 0, o2 = 2, o3 = 3; export default 1", unused_exports()),
+            ("const o1 = 1; export { o1 as o2 }", unused_exports()),
+        ];
+
+        let fail = vec![
+            ("export const o1 = 1", unused_exports()),
+            ("const o2 = 2; export { o2 as o1 }", unused_exports()),
+        ];
+
+        Tester::new(NoUnusedModules::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-unused-modules/file-o.js")
+            .test();
+    }
+
+    {
+        // Linted through a relative path, like `oxlint src`
+        let pass = vec![
+            ("export const b = 2", unused_exports()),
+            (
+                "export const c = 2",
+                Some(json!([{
+                    "unusedExports": true,
+                    "src": src,
+                    "ignoreExports": ["./fixtures/import/no-unused-modules/file-b.js"]
+                }])),
+            ),
+        ];
+        let fail = vec![("export const c = 2", unused_exports())];
+
+        Tester::new(NoUnusedModules::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-unused-modules/file-b.js")
+            .with_relative_rule_path(true)
+            .test();
+    }
+
+    {
+        // `file-0.js` imports `file-l.js` as a namespace, `dynamic-import-js.js` imports
+        // `exports-for-dynamic-js.js` with `import()`
+        let pass = vec![("export const l = 1; export default 2", unused_exports())];
+        let fail = vec![];
+        Tester::new(NoUnusedModules::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-unused-modules/file-l.js")
+            .test();
+
+        let pass = vec![("export const e = 1; export default 2", unused_exports())];
+        let fail = vec![];
+        Tester::new(NoUnusedModules::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-unused-modules/exports-for-dynamic-js.js")
+            .test();
+    }
+
+    {
+        // `file-ts-a-import-type.ts` uses `import type { b }`, `file-ts-h-import-inline-type.ts`
+        // uses `import { type h }`
+        let src = json!(["./fixtures/import/no-unused-modules/typescript"]);
+        let unused_exports = || Some(json!([{ "unusedExports": true, "src": src }]));
+
+        let pass = vec![("export const b = 2", unused_exports())];
+        let fail = vec![("export const c = 2", unused_exports())];
+        Tester::new(NoUnusedModules::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-unused-modules/typescript/file-ts-b-used-as-type.ts")
+            .test();
+
+        let pass = vec![("export const h = 2", unused_exports())];
+        let fail = vec![("export const i = 2", unused_exports())];
+        Tester::new(NoUnusedModules::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-unused-modules/typescript/file-ts-h-used-as-inline-type.ts")
+            .test();
+    }
+
+    {
+        let missing_exports = || Some(json!([{ "missingExports": true }]));
+
+        let pass = vec![
+            ("export const a = 1", missing_exports()),
+            ("const a = 1; export { a }", missing_exports()),
+            ("export default 1", missing_exports()),
+            ("export * from './file-a'", missing_exports()),
+            ("export { o0 } from './file-o'", missing_exports()),
+            ("const a = 1", None),
+        ];
+
+        let fail = vec![
+            ("const a = 1", missing_exports()),
+            ("import a from './file-a'", missing_exports()),
+            ("", missing_exports()),
+        ];
+
+        Tester::new(NoUnusedModules::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-unused-modules/empty_file.js")
+            .test();
+    }
+}
@@ -0,0 +1,159 @@
+use oxc_ast::{ast::JSXChild, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_element_type, get_string_literal_prop_value, has_jsx_prop_lowercase,
+        is_hidden_from_screen_reader,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(accessible-emoji): Emojis should be wrapped in <span>, have role=\"img\", and have an accessible description with aria-label or aria-labelledby.")]
+#[diagnostic(
+    severity(warning),
+    help("Wrap the emoji in `<span role=\"img\" aria-label=\"...\">`.")
+)]
+struct AccessibleEmojiDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct AccessibleEmoji;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that emojis in JSX text are wrapped in a `<span>` with `role="img"` and a
+    /// label provided by `aria-label` or `aria-labelledby`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Emojis have become a common way of communicating content to the end user.
+    /// For screen readers to announce them, they need an accessible name and the `img` role.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <span>🐼</span>
+    /// <i role="img" aria-label="Panda face">🐼</i>
+    ///
+    /// // Good
+    /// <span role="img" aria-label="Panda face">🐼</span>
+    /// <span role="img" aria-labelledby="panda1">🐼</span>
+    /// ```
+    AccessibleEmoji,
+    correctness
+);
+
+impl Rule for AccessibleEmoji {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXElement(jsx_el) = node.kind() else { return };
+
+        let has_emoji = jsx_el.children.iter().any(|child| match child {
+            JSXChild::Text(text) => text.value.chars().any(is_emoji),
+            _ => false,
+        });
+        if !has_emoji {
+            return;
+        }
+
+        let opening_el = &jsx_el.opening_element;
+        if is_hidden_from_screen_reader(ctx, opening_el) {
+            return;
+        }
+
+        let has_label = has_jsx_prop_lowercase(opening_el, "aria-label").is_some()
+            || has_jsx_prop_lowercase(opening_el, "aria-labelledby").is_some();
+        let has_img_role = has_jsx_prop_lowercase(opening_el, "role")
+            .and_then(get_string_literal_prop_value)
+            .is_some_and(|role| role == "img");
+        let is_span = get_element_type(ctx, opening_el).is_some_and(|name| name == "span");
+
+        if !has_label || !has_img_role || !is_span {
+            ctx.diagnostic(AccessibleEmojiDiagnostic(opening_el.span));
+        }
+    }
+}
+
+/// Approximates the `Extended_Pictographic` emoji code points matched by `emoji-regex`.
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF
+            | 0x2600..=0x27BF
+            | 0x2B05..=0x2B07
+            | 0x2B1B..=0x2B1C
+            | 0x2B50
+            | 0x2B55
+            | 0x231A..=0x231B
+            | 0x23E9..=0x23F3
+            | 0x23F8..=0x23FA
+            | 0x2934..=0x2935
+            | 0x3030
+            | 0x303D
+            | 0x3297
+            | 0x3299
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    // https://raw.githubusercontent.com/jsx-eslint/eslint-plugin-jsx-a11y/main/__tests__/src/rules/accessible-emoji-test.js
+    let pass = vec![
+        (r"<div />;", None, None),
+        (r"<span />", None, None),
+        (r"<span>No emoji here!</span>", None, None),
+        (r#"<span role="img" aria-label="Panda face">🐼</span>"#, None, None),
+        (r#"<span role="img" aria-label="Snowman">&#9731;</span>"#, None, None),
+        (r#"<span role="img" aria-labelledby="id1">🐼</span>"#, None, None),
+        (r#"<span role="img" aria-labelledby="id1">&#9731;</span>"#, None, None),
+        (
+            r#"<span role="img" aria-labelledby="id1" aria-label="Snowman">&#9731;</span>"#,
+            None,
+            None,
+        ),
+        (r"<span>{props.emoji}</span>", None, None),
+        (r"<span aria-hidden>{props.emoji}</span>", None, None),
+        (r#"<span aria-hidden="true">🐼</span>"#, None, None),
+        (r"<span aria-hidden>🐼</span>", None, None),
+        (r#"<div aria-hidden="true">🐼</div>"#, None, None),
+        (r#"<input type="hidden">🐼</input>"#, None, None),
+        (
+            r#"<CustomInput type="hidden">🐼</CustomInput>"#,
+            None,
+            Some(serde_json::json!({ "jsx-a11y": { "components": { "CustomInput": "input" } } })),
+        ),
+        (
+            r#"<Box as="span" aria-label="Panda face" role="img">🐼</Box>"#,
+            None,
+            Some(serde_json::json!({ "jsx-a11y": { "polymorphicPropName": "as" } })),
+        ),
+    ];
+
+    let fail = vec![
+        (r"<span>🐼</span>", None, None),
+        (r"<span>foo🐼bar</span>", None, None),
+        (r"<span>foo 🐼 bar</span>", None, None),
+        (r#"<i role="img" aria-label="Panda face">🐼</i>"#, None, None),
+        (r#"<i role="img" aria-labelledby="id1">🐼</i>"#, None, None),
+        (r"<Foo>🐼</Foo>", None, None),
+        (r#"<span aria-hidden="false">🐼</span>"#, None, None),
+        (r#"<CustomInput type="hidden">🐼</CustomInput>"#, None, None),
+        (
+            r#"<Box as="div" aria-label="Panda face" role="img">🐼</Box>"#,
+            None,
+            Some(serde_json::json!({ "jsx-a11y": { "polymorphicPropName": "as" } })),
+        ),
+    ];
+
+    Tester::new(AccessibleEmoji::NAME, pass, fail).with_jsx_a11y_plugin(true).test_and_snapshot();
+}
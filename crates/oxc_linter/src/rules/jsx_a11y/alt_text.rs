@@ -14,7 +14,7 @@ use oxc_span::Span;
 
 use crate::utils::{
     get_element_type, get_prop_value, get_string_literal_prop_value, has_jsx_prop_lowercase,
-    object_has_accessible_child,
+    is_image_element, object_has_accessible_child,
 };
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -170,7 +170,7 @@ impl Rule for AltText {
 
         // <img>
         if let Some(custom_tags) = &self.img {
-            if name == "img" || custom_tags.iter().any(|i| i == name) {
+            if is_image_element(ctx, jsx_el, custom_tags) {
                 img_rule(jsx_el, ctx);
                 return;
             }
@@ -342,6 +342,10 @@ fn test() {
         }])
     }
 
+    fn settings() -> serde_json::Value {
+        serde_json::json!({ "jsx-a11y": { "components": { "Image": "img", "Avatar": "span" } } })
+    }
+
     let pass = vec![
         (r#"<img alt="foo" />;"#, None, None),
         (r#"<img alt={"foo"} />;"#, None, None),
@@ -516,6 +520,12 @@ fn test() {
         (r"<InputImage>Foo</InputImage>", Some(config()), None),
         (r"<InputImage {...this.props} />", Some(config()), None),
         (r#"<Input type="image" />"#, None, None),
+        // image components from settings and from the rule options are combined
+        (r"<Image />", None, Some(settings())),
+        (r"<Picture />", Some(serde_json::json!([{ "img": ["Picture"] }])), Some(settings())),
+        (r"<Image />", Some(serde_json::json!([{ "img": ["Picture"] }])), Some(settings())),
+        // a component named in the rule options is an image even when settings remap it
+        (r"<Avatar />", Some(serde_json::json!([{ "img": ["Avatar"] }])), Some(settings())),
     ];
 
     Tester::new(AltText::NAME, pass, fail).with_jsx_a11y_plugin(true).test_and_snapshot();
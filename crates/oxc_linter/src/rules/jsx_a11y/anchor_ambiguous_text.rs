@@ -0,0 +1,213 @@
+use oxc_ast::{
+    ast::{JSXChild, JSXElement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_element_type, get_string_literal_prop_value, has_jsx_prop_lowercase,
+        is_hidden_from_screen_reader,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(anchor-ambiguous-text): Ambiguous text within anchor.")]
+#[diagnostic(
+    severity(warning),
+    help("Screen reader users rely on link text for context, describe where the link goes instead of using ambiguous words such as \"click here\".")
+)]
+struct AnchorAmbiguousTextDiagnostic(#[label] pub Span);
+
+/// <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/anchor-ambiguous-text.md>
+#[derive(Debug, Clone)]
+pub struct AnchorAmbiguousText(Box<AnchorAmbiguousTextConfig>);
+
+#[derive(Debug, Clone)]
+pub struct AnchorAmbiguousTextConfig {
+    words: Vec<String>,
+}
+
+impl std::ops::Deref for AnchorAmbiguousText {
+    type Target = AnchorAmbiguousTextConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for AnchorAmbiguousText {
+    fn default() -> Self {
+        let words = ["click here", "here", "link", "a link", "learn more"];
+        Self(Box::new(AnchorAmbiguousTextConfig {
+            words: words.into_iter().map(String::from).collect(),
+        }))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that anchor text is not ambiguous out of context, e.g. "click here" or
+    /// "learn more".
+    ///
+    /// The accessible text of the anchor (its `aria-label`, or its text content including the
+    /// `alt` of images) is lower-cased and stripped of punctuation and extra whitespace before
+    /// being compared with the list of ambiguous words.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Screen reader users often navigate a page by jumping between links, where each link is
+    /// announced without its surrounding content. Text such as "here" says nothing about
+    /// where the link goes.
+    ///
+    /// ### Options
+    ///
+    /// * `words`: the ambiguous phrases to report, replacing the default
+    ///   `["click here", "here", "link", "a link", "learn more"]`.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <a>Click here</a>
+    /// <a>learn more.</a>
+    ///
+    /// // Good
+    /// <a>Read the documentation</a>
+    /// <a aria-label="Accessibility guidelines">Learn more</a>
+    /// ```
+    AnchorAmbiguousText,
+    restriction
+);
+
+impl Rule for AnchorAmbiguousText {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(words) =
+            value.get(0).and_then(|v| v.get("words")).and_then(serde_json::Value::as_array)
+        else {
+            return Self::default();
+        };
+        Self(Box::new(AnchorAmbiguousTextConfig {
+            words: words.iter().filter_map(|v| v.as_str().map(String::from)).collect(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXElement(jsx_el) = node.kind() else { return };
+        if get_element_type(ctx, &jsx_el.opening_element).as_deref() != Some("a") {
+            return;
+        }
+        let text = get_accessible_text(ctx, jsx_el);
+        if self.words.iter().any(|word| normalize(word) == text) {
+            ctx.diagnostic(AnchorAmbiguousTextDiagnostic(jsx_el.span));
+        }
+    }
+}
+
+// ref: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/getAccessibleChildText.js
+fn get_accessible_text(ctx: &LintContext, jsx_el: &JSXElement) -> String {
+    let opening_el = &jsx_el.opening_element;
+    if let Some(label) = has_jsx_prop_lowercase(opening_el, "aria-label")
+        .and_then(get_string_literal_prop_value)
+        .filter(|label| !label.is_empty())
+    {
+        return normalize(label);
+    }
+    if get_element_type(ctx, opening_el).as_deref() == Some("img") {
+        if let Some(alt) = has_jsx_prop_lowercase(opening_el, "alt")
+            .and_then(get_string_literal_prop_value)
+            .filter(|alt| !alt.is_empty())
+        {
+            return normalize(alt);
+        }
+    }
+    if is_hidden_from_screen_reader(ctx, opening_el) {
+        return String::new();
+    }
+    let text = jsx_el
+        .children
+        .iter()
+        .map(|child| match child {
+            JSXChild::Text(text) => text.value.to_string(),
+            JSXChild::Element(el) => get_accessible_text(ctx, el),
+            _ => String::new(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    normalize(&text)
+}
+
+/// Lower-cases `text`, strips punctuation and collapses whitespace.
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .replace([',', '.', '?', '¿', '!', '‽', '¡', ';', ':'], "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    fn settings() -> serde_json::Value {
+        json!({ "jsx-a11y": { "components": { "Link": "a", "Image": "img" } } })
+    }
+
+    let pass = vec![
+        ("<a>documentation</a>;", None, None),
+        ("<a>${here}</a>;", None, None),
+        ("<a aria-label=\"tutorial on using eslint-plugin-jsx-a11y\">click here</a>;", None, None),
+        ("<a><span aria-label=\"tutorial on using eslint-plugin-jsx-a11y\">click here</span></a>;", None, None),
+        ("<a><img alt=\"documentation\" /></a>;", None, None),
+        ("<a>{here}</a>;", None, None),
+        ("<a>click here to read the docs</a>;", None, None),
+        ("<a>here is a link</a>;", None, None),
+        ("<Link>documentation</Link>;", None, Some(settings())),
+        ("<Link>click here</Link>;", None, None),
+        ("<div>click here</div>;", None, None),
+        ("<a>click here</a>", Some(json!([{ "words": ["disabling the defaults"] }])), None),
+        ("<a>documentation</a>", Some(json!([{ "words": ["read this"] }])), None),
+    ];
+
+    let fail = vec![
+        ("<a>here</a>;", None, None),
+        ("<a>HERE</a>;", None, None),
+        ("<a>click here</a>;", None, None),
+        ("<a>learn more</a>;", None, None),
+        ("<a>learn      more</a>;", None, None),
+        ("<a>learn more.</a>;", None, None),
+        ("<a>learn more?</a>;", None, None),
+        ("<a>learn more,</a>;", None, None),
+        ("<a>learn more!</a>;", None, None),
+        ("<a>learn more;</a>;", None, None),
+        ("<a>learn more:</a>;", None, None),
+        ("<a>link</a>;", None, None),
+        ("<a>a link</a>;", None, None),
+        ("<a aria-label=\"click here\">something</a>;", None, None),
+        ("<a> a link </a>;", None, None),
+        ("<a>a<i></i> link</a>;", None, None),
+        ("<a><i></i>a link</a>;", None, None),
+        ("<a><span>click</span> here</a>;", None, None),
+        ("<a><span> click </span> here</a>;", None, None),
+        ("<a><span aria-hidden>more text</span>learn more</a>;", None, None),
+        ("<a><img alt=\"click here\" /></a>;", None, None),
+        ("<Link>click here</Link>", None, Some(settings())),
+        ("<a><Image alt=\"click here\" /></a>", None, Some(settings())),
+        ("<a>a disallowed word</a>", Some(json!([{ "words": ["a disallowed word"] }])), None),
+        ("<a>Read This!</a>", Some(json!([{ "words": ["read this"] }])), None),
+    ];
+
+    Tester::new(AnchorAmbiguousText::NAME, pass, fail)
+        .with_jsx_a11y_plugin(true)
+        .test_and_snapshot();
+}
@@ -139,12 +139,14 @@ fn test() {
         (r"<input aria-activedescendant={someID} tabIndex={-1} />;", None, None, None),
         (r"<div aria-activedescendant={someID} tabIndex={-1} />;", None, None, None),
         (r"<div aria-activedescendant={someID} tabIndex='-1' />;", None, None, None),
+        (r"<div aria-activedescendant={someID} tabIndex={tabIndex} />;", None, None, None),
         (r"<input aria-activedescendant={someID} tabIndex={-1} />;", None, None, None),
     ];
 
     let fail = vec![
         (r"<div aria-activedescendant={someID} />;", None, None, None),
         (r"<CustomComponent aria-activedescendant={someID} />;", None, Some(settings()), None),
+        (r"<div aria-activedescendant={someID} tabIndex={-2} />;", None, None, None),
     ];
 
     Tester::new(AriaActivedescendantHasTabindex::NAME, pass, fail).test_and_snapshot();
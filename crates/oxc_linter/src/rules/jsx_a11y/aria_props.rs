@@ -1,20 +1,23 @@
-use oxc_ast::{ast::JSXAttributeItem, AstKind};
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXElementName},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
-    context::LintContext, globals::VALID_ARIA_PROPS, rule::Rule, utils::get_jsx_attribute_name,
-    AstNode,
+    context::LintContext, fixer::Fix, globals::VALID_ARIA_PROPS, rule::Rule,
+    utils::get_jsx_attribute_name, AstNode,
 };
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-jsx-a11y(aria-props): Invalid ARIA prop.")]
-#[diagnostic(severity(warning), help("`{1}` is an invalid ARIA attribute."))]
-struct AriaPropsDiagnostic(#[label] pub Span, String);
+#[diagnostic(severity(warning), help("`{1}` is an invalid ARIA attribute.{2}"))]
+struct AriaPropsDiagnostic(#[label] pub Span, String, String);
 
 #[derive(Debug, Default, Clone)]
 pub struct AriaProps;
@@ -23,6 +26,9 @@ declare_oxc_lint!(
     /// ### What it does
     /// Enforces that elements do not use invalid ARIA attributes.
     ///
+    /// React-style camelCased ARIA props on DOM elements, such as `ariaLabel`, are reported
+    /// as well and fixed to their hyphenated form.
+    ///
     /// ### Why is this bad?
     /// Using invalid ARIA attributes can mislead screen readers and other assistive technologies.
     /// It may cause the accessibility features of the website to fail, making it difficult
@@ -32,9 +38,11 @@ declare_oxc_lint!(
     /// ```javascript
     /// // Bad
     /// <input aria-labeledby="address_label" />
+    /// <input ariaLabel="Address" />
     ///
     /// // Good
     /// <input aria-labelledby="address_label" />
+    /// <input aria-label="Address" />
     /// ```
     AriaProps,
     correctness
@@ -42,14 +50,78 @@ declare_oxc_lint!(
 impl Rule for AriaProps {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) = node.kind() {
-            let name = get_jsx_attribute_name(&attr.name).to_lowercase();
+            let name = get_jsx_attribute_name(&attr.name);
+            if let Some(hyphenated) = get_hyphenated_aria_prop(&name) {
+                if is_dom_element(node, ctx) {
+                    let attr_name_span = attr.name.span();
+                    ctx.diagnostic_with_fix(
+                        AriaPropsDiagnostic(
+                            attr.span,
+                            name,
+                            format!(" Did you mean `{hyphenated}`?"),
+                        ),
+                        || Fix::new(hyphenated, attr_name_span),
+                    );
+                }
+                return;
+            }
+            let name = name.to_lowercase();
             if name.starts_with("aria-") && !VALID_ARIA_PROPS.contains(&name) {
-                ctx.diagnostic(AriaPropsDiagnostic(attr.span, name));
+                let suggestion = get_suggestion(&name)
+                    .map(|suggestion| format!(" Did you mean `{suggestion}`?"))
+                    .unwrap_or_default();
+                ctx.diagnostic(AriaPropsDiagnostic(attr.span, name, suggestion));
             }
         }
     }
 }
 
+/// Returns the hyphenated form of a camelCased ARIA prop, e.g. `ariaLabel` => `aria-label`,
+/// if it is a valid ARIA attribute.
+fn get_hyphenated_aria_prop(name: &str) -> Option<&'static str> {
+    let rest = name.strip_prefix("aria")?;
+    if !rest.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return None;
+    }
+    VALID_ARIA_PROPS.get_key(format!("aria-{}", rest.to_ascii_lowercase()).as_str()).copied()
+}
+
+/// Whether the attribute belongs to a DOM element like `<div>`, components may accept
+/// camelCased props of their own.
+fn is_dom_element(node: &AstNode, ctx: &LintContext) -> bool {
+    matches!(
+        ctx.nodes().parent_kind(node.id()),
+        Some(AstKind::JSXOpeningElement(el))
+            if matches!(&el.name, JSXElementName::Identifier(ident) if ident.name.starts_with(|c: char| c.is_ascii_lowercase()))
+    )
+}
+
+/// Returns the closest valid ARIA attribute within an edit distance of 2.
+fn get_suggestion(name: &str) -> Option<&'static str> {
+    VALID_ARIA_PROPS
+        .iter()
+        .map(|prop| (edit_distance(name, prop), *prop))
+        .filter(|(distance, _)| *distance <= 2)
+        .min()
+        .map(|(_, prop)| prop)
+}
+
+/// Levenshtein distance between two ASCII attribute names.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut prev_diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev_diagonal + usize::from(ca != *cb);
+            prev_diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(prev_diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -63,13 +135,33 @@ fn test() {
         r#"<div fooaria-hidden="true"></div>"#,
         r"<Bar baz />",
         r#"<input type="text" aria-errormessage="foobar" />"#,
+        r#"<div aria-labelledby="foobar" aria-describedby="baz" />"#,
+        r#"<div aria-hidden="true" aria-live="polite" aria-expanded={expanded} />"#,
+        r#"<input aria-autocomplete="list" aria-activedescendant="opt" aria-invalid />"#,
+        r#"<div aria-label="foo" />"#,
+        r#"<div data-foo="bar" />"#,
+        r#"<div data-aria-label="bar" />"#,
+        r#"<div ariaFoo="bar" />"#,
+        r#"<Foo ariaLabel="bar" />"#,
     ];
 
     let fail = vec![
         r#"<div aria-="foobar" />"#,
         r#"<div aria-labeledby="foobar" />"#,
         r#"<div aria-skldjfaria-klajsd="foobar" />"#,
+        r#"<div aria-foo="true" />"#,
+        r#"<div aria-describeby="foobar" />"#,
+        r#"<div aria-hiden="true" />"#,
+        r#"<div ariaLabel="foo" />"#,
+        r"<div ariaHidden />",
+        r#"<input ariaLabelledBy="foo" />"#,
+    ];
+
+    let fix = vec![
+        (r#"<div ariaLabel="foo" />"#, r#"<div aria-label="foo" />"#, None),
+        (r"<div ariaHidden />", r"<div aria-hidden />", None),
+        (r#"<input ariaLabelledBy="foo" />"#, r#"<input aria-labelledby="foo" />"#, None),
     ];
 
-    Tester::new(AriaProps::NAME, pass, fail).test_and_snapshot();
+    Tester::new(AriaProps::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -8,6 +8,7 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use rustc_hash::FxHashSet;
 
 use crate::{
     context::LintContext,
@@ -28,10 +29,20 @@ struct AriaRoleDiagnostic(#[label] pub Span, String);
 #[derive(Debug, Default, Clone)]
 pub struct AriaRole(Box<AriaRoleConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct AriaRoleConfig {
     ignore_non_dom: bool,
-    allowed_invalid_roles: Vec<String>,
+    /// `VALID_ARIA_ROLES` merged with the configured `allowedInvalidRoles`.
+    valid_roles: FxHashSet<String>,
+}
+
+impl Default for AriaRoleConfig {
+    fn default() -> Self {
+        Self {
+            ignore_non_dom: false,
+            valid_roles: VALID_ARIA_ROLES.iter().map(ToString::to_string).collect(),
+        }
+    }
 }
 
 impl std::ops::Deref for AriaRole {
@@ -96,26 +107,21 @@ declare_oxc_lint!(
 
 impl Rule for AriaRole {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut ignore_non_dom = false;
-        let mut allowed_invalid_roles: Vec<String> = vec![];
+        let mut config = AriaRoleConfig::default();
 
-        let _ = value.as_array().unwrap().iter().find(|v| {
-            if let serde_json::Value::Object(obj) = v {
-                if let Some(serde_json::Value::Bool(val)) = obj.get("ignoreNonDOM") {
-                    ignore_non_dom = *val;
-                }
-
-                if let Some(serde_json::Value::Array(val)) = obj.get("allowedInvalidRoles") {
-                    allowed_invalid_roles =
-                        val.iter().map(|v| v.as_str().unwrap().to_string()).collect();
-                }
+        if let Some(obj) = value.as_array().and_then(|arr| arr.iter().find_map(|v| v.as_object())) {
+            if let Some(serde_json::Value::Bool(val)) = obj.get("ignoreNonDOM") {
+                config.ignore_non_dom = *val;
+            }
 
-                return true;
+            if let Some(serde_json::Value::Array(val)) = obj.get("allowedInvalidRoles") {
+                config
+                    .valid_roles
+                    .extend(val.iter().filter_map(|v| v.as_str().map(ToString::to_string)));
             }
-            false
-        });
+        }
 
-        Self(Box::new(AriaRoleConfig { ignore_non_dom, allowed_invalid_roles }))
+        Self(Box::new(config))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -145,10 +151,9 @@ impl Rule for AriaRole {
                     Some(JSXAttributeValue::StringLiteral(str)) => {
                         let words_str = String::from(str.value.as_str());
                         let words = words_str.split_whitespace();
-                        if let Some(error_prop) = words.into_iter().find(|word| {
-                            !VALID_ARIA_ROLES.contains(word)
-                                && !self.allowed_invalid_roles.contains(&(*word).to_string())
-                        }) {
+                        if let Some(error_prop) =
+                            words.into_iter().find(|word| !self.valid_roles.contains(*word))
+                        {
                             ctx.diagnostic(AriaRoleDiagnostic(
                                 str.span,
                                 format!(", `{error_prop}` is an invalid aria role"),
@@ -212,6 +217,7 @@ fn test() {
         ),
         ("<Foo role='bar' />", Some(ignore_non_dom_schema()), None, None),
         ("<fakeDOM role='bar' />", Some(ignore_non_dom_schema()), None, None),
+        ("<Foo role='invalid-role' />", Some(allowed_invalid_roles()), None, None),
         ("<img role='presentation' />", Some(ignore_non_dom_schema()), None, None),
         ("<Div role='button' />", None, Some(settings()), None),
         ("<Box asChild='div' role='button' />", None, Some(settings()), None),
@@ -229,6 +235,8 @@ fn test() {
         ("<div role='doc-endnotes range'></div>", None, None, None),
         ("<div role />", None, None, None),
         ("<div role='unknown-invalid-role' />", Some(allowed_invalid_roles()), None, None),
+        ("<div role='invalid-role' />", None, None, None),
+        ("<div role='invalid-role' />", Some(ignore_non_dom_schema()), None, None),
         ("<div role={null}></div>", None, None, None),
         ("<Foo role='datepicker' />", None, None, None),
         ("<Foo role='Button' />", None, None, None),
@@ -1,7 +1,7 @@
 use crate::{
     context::LintContext,
-    rule::Rule,
-    utils::{get_element_type, has_jsx_prop_lowercase},
+    rule::{deserialize_rule_options, Rule},
+    utils::{get_element_type, get_string_literal_prop_value, has_jsx_prop_lowercase},
     AstNode,
 };
 use oxc_ast::{
@@ -15,6 +15,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::{phf_map, phf_set};
+use serde::Deserialize;
 #[derive(Debug, Error, Diagnostic)]
 #[error(
     "eslint-plugin-jsx-a11y(autocomplete-valid): `{autocomplete}` is not a valid value for autocomplete."
@@ -26,6 +27,21 @@ struct AutocompleteValidDiagnostic {
     pub autocomplete: String,
 }
 
+#[derive(Debug, Error, Diagnostic)]
+#[error(
+    "eslint-plugin-jsx-a11y(autocomplete-valid): `{autocomplete}` does not apply to an input of type `{input_type}`."
+)]
+#[diagnostic(
+    severity(warning),
+    help("Change the input type, or use an autocomplete value that applies to `{input_type}` inputs.")
+)]
+struct AutocompleteInputTypeDiagnostic {
+    #[label]
+    pub span: Span,
+    pub autocomplete: String,
+    pub input_type: String,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct AutocompleteValid(Box<AutocompleteValidConfig>);
 declare_oxc_lint!(
@@ -35,6 +51,15 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// Incorrectly using the autocomplete attribute may decrease the accessibility of the website for users.
     ///
+    /// ### Options
+    ///
+    /// * `inputComponents`: custom components that are checked like `<input>`.
+    /// * `additionalValidValues`: extra autocomplete tokens that are accepted on their own, e.g.
+    ///   the custom tokens of a design system.
+    /// * `checkInputType`: also report autocomplete values that don't apply to the static `type`
+    ///   of the input, e.g. `cc-number` on a checkbox. `text`, `search` and `hidden` inputs
+    ///   accept every value. Defaults to `false`.
+    ///
     /// ### Example
     /// ```javascript
     /// // Bad
@@ -42,6 +67,10 @@ declare_oxc_lint!(
     ///
     /// // Good
     /// <input autocomplete="name" />
+    /// <input autocomplete="username webauthn" />
+    ///
+    /// // Bad, with `checkInputType`
+    /// <input type="checkbox" autocomplete="cc-number" />
     /// ```
     AutocompleteValid,
     correctness
@@ -50,6 +79,8 @@ declare_oxc_lint!(
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AutocompleteValidConfig {
     input_components: Vec<String>,
+    additional_valid_values: Vec<String>,
+    check_input_type: bool,
 }
 
 impl std::ops::Deref for AutocompleteValid {
@@ -60,9 +91,21 @@ impl std::ops::Deref for AutocompleteValid {
     }
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct AutocompleteValidOptions {
+    input_components: Vec<String>,
+    additional_valid_values: Vec<String>,
+    check_input_type: bool,
+}
+
 impl std::default::Default for AutocompleteValidConfig {
     fn default() -> Self {
-        Self { input_components: vec!["input".to_string()] }
+        Self {
+            input_components: vec!["input".to_string()],
+            additional_valid_values: vec![],
+            check_input_type: false,
+        }
     }
 }
 
@@ -115,7 +158,12 @@ static VALID_AUTOCOMPLETE_VALUES: phf::Set<&'static str> = phf_set! {
     "impp",
     "url",
     "photo",
-    "webauthn",
+};
+
+/// Fields that may be followed by the `webauthn` credential type token.
+static WEBAUTHN_FIELDS: phf::Set<&'static str> = phf_set! {
+    "username",
+    "current-password",
 };
 
 static BILLING: phf::Set<&'static str> = phf_set! {
@@ -151,10 +199,20 @@ static VALID_AUTOCOMPLETE_COMBINATIONS: phf::Map<&'static str, &'static phf::Set
     "shipping" => &SHIPPING,
 };
 
-fn is_valid_autocomplete_value(value: &str) -> bool {
-    let parts: Vec<&str> = value.split_whitespace().collect();
+fn is_valid_autocomplete_value(value: &str, additional_valid_values: &[String]) -> bool {
+    let mut parts: Vec<&str> = value.split_whitespace().collect();
+    // `webauthn` can only be the last token, following a credential field.
+    if parts.last() == Some(&"webauthn") {
+        parts.pop();
+        if !parts.last().is_some_and(|field| WEBAUTHN_FIELDS.contains(field)) {
+            return false;
+        }
+    }
     match parts.len() {
-        1 => VALID_AUTOCOMPLETE_VALUES.contains(parts[0]),
+        1 => {
+            VALID_AUTOCOMPLETE_VALUES.contains(parts[0])
+                || additional_valid_values.iter().any(|v| v == parts[0])
+        }
         2 => VALID_AUTOCOMPLETE_COMBINATIONS
             .get(parts[0])
             .map_or(false, |valid_suffixes| valid_suffixes.contains(parts[1])),
@@ -162,22 +220,57 @@ fn is_valid_autocomplete_value(value: &str) -> bool {
     }
 }
 
+/// Input types an autofill field applies to besides `text`, `search` and `hidden`, following the
+/// control groups of the HTML spec. Numeric fields also accept `tel`, which is commonly used for
+/// its numeric keypad.
+/// <https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill-field>
+fn field_input_types(field: &str) -> &'static [&'static str] {
+    match field {
+        "username" | "email" => &["email"],
+        "new-password" | "current-password" | "one-time-code" => &["password"],
+        "tel" => &["tel"],
+        "url" | "photo" | "impp" => &["url"],
+        "cc-number" | "cc-exp-month" | "cc-exp-year" | "cc-csc" | "transaction-amount"
+        | "bday-day" | "bday-month" | "bday-year" => &["number", "tel"],
+        "cc-exp" => &["month"],
+        "bday" => &["date"],
+        _ => &[],
+    }
+}
+
+/// Whether the autofill field of a valid autocomplete `value` applies to `input_type`.
+/// Custom values and `on`/`off` are not checked.
+fn applies_to_input_type(value: &str, input_type: &str) -> bool {
+    let Some(field) = value.split_whitespace().filter(|token| *token != "webauthn").last() else {
+        return true;
+    };
+    if matches!(field, "on" | "off") || !VALID_AUTOCOMPLETE_VALUES.contains(field) {
+        return true;
+    }
+    matches!(input_type, "text" | "search" | "hidden")
+        || field_input_types(field).contains(&input_type)
+}
+
 impl Rule for AutocompleteValid {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut input_components: Vec<String> = vec!["input".to_string()];
-        if let Some(config) = value.get(0) {
-            if let Some(serde_json::Value::Array(components)) = config.get("inputComponents") {
-                input_components = components
-                    .iter()
-                    .filter_map(|c| c.as_str().map(std::string::ToString::to_string))
-                    .collect();
-            }
-        }
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        let AutocompleteValidOptions {
+            mut input_components,
+            additional_valid_values,
+            check_input_type,
+        } = deserialize_rule_options(&value)?;
 
         // Add default input component
         input_components.push("input".to_string());
 
-        Self(Box::new(AutocompleteValidConfig { input_components }))
+        Ok(Self(Box::new(AutocompleteValidConfig {
+            input_components,
+            additional_valid_values,
+            check_input_type,
+        })))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -200,11 +293,29 @@ impl Rule for AutocompleteValid {
                 _ => return,
             };
             let value = autocomplete_values.value.to_string();
-            if !is_valid_autocomplete_value(&value) {
+            if !is_valid_autocomplete_value(&value, &self.additional_valid_values) {
                 ctx.diagnostic(AutocompleteValidDiagnostic {
                     span: attr.span,
                     autocomplete: value,
                 });
+                return;
+            }
+
+            if !self.check_input_type {
+                return;
+            }
+            let Some(input_type) = has_jsx_prop_lowercase(jsx_el, "type")
+                .and_then(get_string_literal_prop_value)
+                .map(str::to_lowercase)
+            else {
+                return;
+            };
+            if !applies_to_input_type(&value, &input_type) {
+                ctx.diagnostic(AutocompleteInputTypeDiagnostic {
+                    span: attr.span,
+                    autocomplete: value,
+                    input_type,
+                });
             }
         }
     }
@@ -225,6 +336,8 @@ fn test() {
         })
     }
 
+    let check_input_type = || Some(serde_json::json!([{ "checkInputType": true }]));
+
     let pass = vec![
         ("<input type='text' />;", None, None),
         ("<input type='text' autocomplete='name' />;", None, None),
@@ -250,6 +363,41 @@ fn test() {
             Some(serde_json::json!([{ "inputComponents": ["Foo"] }])),
             None,
         ),
+        (
+            "<input type='text' autocomplete='custom-token' />;",
+            Some(serde_json::json!([{ "additionalValidValues": ["custom-token"] }])),
+            None,
+        ),
+        (
+            "<input type='text' autocomplete='billing postal-code' />;",
+            Some(serde_json::json!([{ "additionalValidValues": ["custom-token"] }])),
+            None,
+        ),
+        (
+            "<input type='text' autocomplete='email' />;",
+            Some(serde_json::json!([{ "additionalValidValues": ["custom-token"] }])),
+            None,
+        ),
+        ("<input type='text' autocomplete='username webauthn' />;", None, None),
+        ("<input type='password' autocomplete='current-password webauthn' />;", None, None),
+        ("<input type='checkbox' autocomplete='cc-number' />;", None, None),
+        ("<input type='text' autocomplete='cc-number' />;", check_input_type(), None),
+        ("<input type='tel' autocomplete='cc-number' />;", check_input_type(), None),
+        ("<input type='TEL' autocomplete='tel' />;", check_input_type(), None),
+        ("<input autocomplete='cc-number' />;", check_input_type(), None),
+        ("<input type='email' autocomplete='username webauthn' />;", check_input_type(), None),
+        ("<input type='password' autocomplete='new-password' />;", check_input_type(), None),
+        ("<input type='month' autocomplete='cc-exp' />;", check_input_type(), None),
+        ("<input type='checkbox' autocomplete='off' />;", check_input_type(), None),
+        ("<input type={type} autocomplete='cc-number' />;", check_input_type(), None),
+        (
+            "<input type='checkbox' autocomplete='custom-token' />;",
+            Some(serde_json::json!([{
+                "checkInputType": true,
+                "additionalValidValues": ["custom-token"]
+            }])),
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -263,7 +411,65 @@ fn test() {
             None,
         ),
         ("<Input type='text' autocomplete='baz' />;", None, Some(settings())),
+        ("<input type='text' autocomplete='custom-token' />;", None, None),
+        (
+            "<input type='text' autocomplete='other-token' />;",
+            Some(serde_json::json!([{ "additionalValidValues": ["custom-token"] }])),
+            None,
+        ),
+        (
+            "<input type='text' autocomplete='billing custom-token' />;",
+            Some(serde_json::json!([{ "additionalValidValues": ["custom-token"] }])),
+            None,
+        ),
+        ("<input type='text' autocomplete='webauthn' />;", None, None),
+        ("<input type='text' autocomplete='name webauthn' />;", None, None),
+        ("<input type='text' autocomplete='webauthn username' />;", None, None),
+        ("<input type='text' autocomplete='username webauthn webauthn' />;", None, None),
+        ("<input type='text' autocomplete='billing postal-code webauthn' />;", None, None),
+        ("<input type='checkbox' autocomplete='cc-number' />;", check_input_type(), None),
+        ("<input type='date' autocomplete='email' />;", check_input_type(), None),
+        ("<input type='number' autocomplete='shipping postal-code' />;", check_input_type(), None),
+        ("<input type='password' autocomplete='username' />;", check_input_type(), None),
+        (
+            "<Foo type='radio' autocomplete='name'></Foo>;",
+            Some(serde_json::json!([{ "checkInputType": true, "inputComponents": ["Foo"] }])),
+            None,
+        ),
     ];
 
     Tester::new(AutocompleteValid::NAME, pass, fail).test_and_snapshot();
 }
+
+#[test]
+fn test_configuration() {
+    use crate::rules::AutocompleteValid;
+
+    let rule = AutocompleteValid::try_from_configuration(serde_json::json!([
+        { "inputComponents": ["Foo", "Bar"] }
+    ]))
+    .unwrap();
+    assert_eq!(rule.input_components, vec!["Foo", "Bar", "input"]);
+    assert!(rule.additional_valid_values.is_empty());
+    assert!(!rule.check_input_type);
+
+    let rule = AutocompleteValid::try_from_configuration(serde_json::json!([
+        { "additionalValidValues": ["custom-token"] }
+    ]))
+    .unwrap();
+    assert_eq!(rule.additional_valid_values, vec!["custom-token"]);
+
+    let rule = AutocompleteValid::try_from_configuration(serde_json::json!([
+        { "checkInputType": true }
+    ]))
+    .unwrap();
+    assert!(rule.check_input_type);
+
+    let rule = AutocompleteValid::try_from_configuration(serde_json::json!([])).unwrap();
+    assert_eq!(rule.input_components, vec!["input"]);
+
+    assert!(AutocompleteValid::try_from_configuration(serde_json::json!([
+        { "inputComponents": "Foo" }
+    ]))
+    .is_err());
+}
@@ -1,7 +1,5 @@
 use oxc_ast::{
-    ast::{
-        JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXExpression, JSXExpressionContainer,
-    },
+    ast::{JSXAttributeItem, JSXAttributeValue, JSXExpression, JSXExpressionContainer},
     AstKind,
 };
 use oxc_diagnostics::{
@@ -9,12 +7,12 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop_lowercase},
+    utils::{get_prop_value, has_jsx_prop_lowercase, is_html_element},
     AstNode,
 };
 
@@ -62,20 +60,12 @@ impl Rule for HtmlHasLang {
             return;
         };
 
-        let Some(element_type) = get_element_type(ctx, jsx_el) else {
-            return;
-        };
-
-        if element_type != "html" {
+        if !is_html_element(ctx, jsx_el) {
             return;
         }
 
-        let JSXElementName::Identifier(identifier) = &jsx_el.name else {
-            return;
-        };
-
         has_jsx_prop_lowercase(jsx_el, "lang").map_or_else(
-            || ctx.diagnostic(HtmlHasLangDiagnostic::MissingLangProp(identifier.span)),
+            || ctx.diagnostic(HtmlHasLangDiagnostic::MissingLangProp(jsx_el.name.span())),
             |lang_prop| {
                 if !is_valid_lang_prop(lang_prop) {
                     ctx.diagnostic(HtmlHasLangDiagnostic::MissingLangValue(jsx_el.span));
@@ -105,6 +95,8 @@ fn test() {
             "jsx-a11y": {
                 "components": {
                     "HTMLTop": "html",
+                    "Html": "html",
+                    "Body": "body",
                 }
             }
         })
@@ -118,6 +110,9 @@ fn test() {
         (r"<html lang />;", None, None, None),
         (r"<HTML />;", None, None, None),
         ("<HTMLTop lang='en' />", None, Some(settings()), None),
+        ("<Html lang='en' />", None, Some(settings()), None),
+        ("<Body />", None, Some(settings()), None),
+        ("<Head />", None, Some(settings()), None),
     ];
 
     let fail = vec![
@@ -126,6 +121,7 @@ fn test() {
         (r"<html lang={undefined} />;", None, None, None),
         (r#"<html lang="" />;"#, None, None, None),
         ("<HTMLTop />", None, Some(settings()), None),
+        ("<Html />", None, Some(settings()), None),
     ];
 
     Tester::new(HtmlHasLang::NAME, pass, fail).with_jsx_a11y_plugin(true).test_and_snapshot();
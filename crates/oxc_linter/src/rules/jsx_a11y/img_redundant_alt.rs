@@ -15,7 +15,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
 use crate::utils::{
-    get_element_type, get_prop_value, has_jsx_prop_lowercase, is_hidden_from_screen_reader,
+    get_prop_value, has_jsx_prop_lowercase, is_hidden_from_screen_reader, is_image_element,
 };
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -29,7 +29,8 @@ pub struct ImgRedundantAlt(Box<ImgRedundantAltConfig>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ImgRedundantAltConfig {
-    types_to_validate: Vec<String>,
+    /// Custom image components, in addition to `img` and the ones mapped in settings.
+    components: Vec<String>,
     redundant_words: Vec<String>,
 }
 
@@ -44,10 +45,7 @@ impl std::ops::Deref for ImgRedundantAlt {
 impl Default for ImgRedundantAltConfig {
     fn default() -> Self {
         Self {
-            types_to_validate: COMPONENTS_FIXED_TO_VALIDATE
-                .iter()
-                .map(|&s| s.to_string())
-                .collect(),
+            components: vec![],
             redundant_words: REDUNDANT_WORDS.iter().map(|&s| s.to_string()).collect(),
         }
     }
@@ -85,7 +83,6 @@ declare_oxc_lint!(
     ImgRedundantAlt,
     correctness
 );
-const COMPONENTS_FIXED_TO_VALIDATE: [&str; 1] = ["img"];
 const REDUNDANT_WORDS: [&str; 3] = ["image", "photo", "picture"];
 
 impl Rule for ImgRedundantAlt {
@@ -94,7 +91,7 @@ impl Rule for ImgRedundantAlt {
         if let Some(config) = value.get(0) {
             if let Some(components) = config.get("components").and_then(|v| v.as_array()) {
                 img_redundant_alt
-                    .types_to_validate
+                    .components
                     .extend(components.iter().filter_map(|v| v.as_str().map(ToString::to_string)));
             }
 
@@ -109,11 +106,7 @@ impl Rule for ImgRedundantAlt {
     }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
-        let Some(element_type) = get_element_type(ctx, jsx_el) else {
-            return;
-        };
-
-        if !self.types_to_validate.iter().any(|comp| comp == &element_type) {
+        if !is_image_element(ctx, jsx_el, &self.components) {
             return;
         }
 
@@ -204,6 +197,7 @@ fn test() {
             "jsx-a11y": {
                 "components": {
                     "Image": "img",
+                    "Avatar": "span",
                 }
             }
         })
@@ -243,6 +237,12 @@ fn test() {
         (r"<img alt='ImageMagick' />;", None, None),
         (r"<Image alt='Photo of a friend' />", None, None),
         (r"<Image alt='Foo' />", None, Some(settings())),
+        (
+            r"<Picture alt='Foo' />",
+            Some(serde_json::json!([{ "components": ["Picture"] }])),
+            Some(settings()),
+        ),
+        (r"<Picture alt='Photo of a friend' />", None, Some(settings())),
     ];
 
     let fail = vec![
@@ -271,6 +271,23 @@ fn test() {
         (r"<img alt='Word2' />;", Some(array()), None),
         (r"<Image alt='Word1' />;", Some(array()), None),
         (r"<Image alt='Word2' />;", Some(array()), None),
+        // image components from settings and from the rule options are combined
+        (
+            r"<Picture alt='photo' />",
+            Some(serde_json::json!([{ "components": ["Picture"] }])),
+            Some(settings()),
+        ),
+        (
+            r"<Image alt='photo' />",
+            Some(serde_json::json!([{ "components": ["Picture"] }])),
+            Some(settings()),
+        ),
+        // a component named in the rule options is an image even when settings remap it
+        (
+            r"<Avatar alt='photo' />",
+            Some(serde_json::json!([{ "components": ["Avatar"] }])),
+            Some(settings()),
+        ),
     ];
 
     Tester::new(ImgRedundantAlt::NAME, pass, fail).with_jsx_a11y_plugin(true).test_and_snapshot();
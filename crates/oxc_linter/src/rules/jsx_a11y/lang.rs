@@ -1,8 +1,6 @@
 use language_tags::LanguageTag;
 use oxc_ast::{
-    ast::{
-        JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXExpression, JSXExpressionContainer,
-    },
+    ast::{JSXAttributeItem, JSXAttributeValue, JSXExpression, JSXExpressionContainer},
     AstKind,
 };
 use oxc_diagnostics::{
@@ -10,12 +8,12 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop_lowercase},
+    utils::{get_prop_value, has_jsx_prop_lowercase, is_html_element},
     AstNode,
 };
 
@@ -67,20 +65,12 @@ impl Rule for Lang {
             return;
         };
 
-        let Some(element_type) = get_element_type(ctx, jsx_el) else {
-            return;
-        };
-
-        if element_type != "html" {
+        if !is_html_element(ctx, jsx_el) {
             return;
         }
 
-        let JSXElementName::Identifier(identifier) = &jsx_el.name else {
-            return;
-        };
-
         has_jsx_prop_lowercase(jsx_el, "lang").map_or_else(
-            || ctx.diagnostic(LangDiagnostic(identifier.span)),
+            || ctx.diagnostic(LangDiagnostic(jsx_el.name.span())),
             |lang_prop| {
                 if !is_valid_lang_prop(lang_prop) {
                     if let JSXAttributeItem::Attribute(attr) = lang_prop {
@@ -116,6 +106,8 @@ fn test() {
                 "polymorphicPropName": "as",
                 "components": {
                     "Foo": "html",
+                    "Html": "html",
+                    "Body": "body",
                 }
             }
         })
@@ -136,6 +128,9 @@ fn test() {
         ("<Foo lang={undefined} />", None, None, None),
         ("<Foo lang='en' />", None, Some(settings()), None),
         ("<Box as='html' lang='en'  />", None, Some(settings()), None),
+        ("<Html lang='en' />", None, Some(settings()), None),
+        ("<Body lang='foo' />", None, Some(settings()), None),
+        ("<Head lang='foo' />", None, Some(settings()), None),
     ];
 
     let fail = vec![
@@ -144,6 +139,8 @@ fn test() {
         ("<html lang={undefined} />", None, None, None),
         ("<Foo lang={undefined} />", None, Some(settings()), None),
         ("<Box as='html' lang='foo' />", None, Some(settings()), None),
+        ("<Html lang='foo' />", None, Some(settings()), None),
+        ("<Html />", None, Some(settings()), None),
     ];
 
     Tester::new(Lang::NAME, pass, fail).test_and_snapshot();
@@ -9,7 +9,10 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, utils::has_jsx_prop_lowercase, AstNode};
+use crate::{
+    context::LintContext, fixer::remove_jsx_attribute, rule::Rule, utils::has_jsx_prop_lowercase,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-jsx-a11y(no-access-key): No access key attribute allowed.")]
@@ -46,7 +49,9 @@ impl Rule for NoAccessKey {
         {
             match attr.value.as_ref() {
                 Some(JSXAttributeValue::StringLiteral(_)) => {
-                    ctx.diagnostic(NoAccessKeyDiagnostic(attr.span));
+                    ctx.diagnostic_with_fix(NoAccessKeyDiagnostic(attr.span), || {
+                        remove_jsx_attribute(attr, ctx)
+                    });
                 }
                 Some(JSXAttributeValue::ExpressionContainer(JSXExpressionContainer {
                     expression: JSXExpression::Expression(expr),
@@ -55,7 +60,9 @@ impl Rule for NoAccessKey {
                     if expr.is_identifier_reference() & expr.is_undefined() {
                         return;
                     }
-                    ctx.diagnostic(NoAccessKeyDiagnostic(attr.span));
+                    ctx.diagnostic_with_fix(NoAccessKeyDiagnostic(attr.span), || {
+                        remove_jsx_attribute(attr, ctx)
+                    });
                 }
                 _ => {}
             }
@@ -83,5 +90,20 @@ fn test() {
         r"<div accessKey={`${undefined}${undefined}`} />",
     ];
 
-    Tester::new(NoAccessKey::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r#"<div accessKey="h" />"#, r"<div />", None),
+        (r#"<div accessKey="h" {...props} />"#, r"<div {...props} />", None),
+        (
+            r#"<div id="foo" accessKey={"y"} role="button" />"#,
+            r#"<div id="foo" role="button" />"#,
+            None,
+        ),
+        (
+            r"<div role='button' accessKey={accessKey}>text</div>",
+            r"<div role='button'>text</div>",
+            None,
+        ),
+    ];
+
+    Tester::new(NoAccessKey::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
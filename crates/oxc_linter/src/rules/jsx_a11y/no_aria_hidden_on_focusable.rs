@@ -11,6 +11,7 @@ use oxc_span::Span;
 
 use crate::{
     context::LintContext,
+    fixer::remove_jsx_attribute,
     rule::Rule,
     utils::{get_element_type, has_jsx_prop_lowercase, parse_jsx_value},
     AstNode,
@@ -49,7 +50,10 @@ impl Rule for NoAriaHiddenOnFocusable {
         if let Some(aria_hidden_prop) = has_jsx_prop_lowercase(jsx_el, "aria-hidden") {
             if is_aria_hidden_true(aria_hidden_prop) && is_focusable(ctx, jsx_el) {
                 if let JSXAttributeItem::Attribute(boxed_attr) = aria_hidden_prop {
-                    ctx.diagnostic(NoAriaHiddenOnFocusableDiagnostic(boxed_attr.span));
+                    ctx.diagnostic_with_fix(
+                        NoAriaHiddenOnFocusableDiagnostic(boxed_attr.span),
+                        || remove_jsx_attribute(boxed_attr, ctx),
+                    );
                 }
             }
         }
@@ -114,6 +118,8 @@ fn test() {
         "<img aria-hidden=\"true\" />",
         "<a aria-hidden=\"false\" href=\"\" />",
         "<button aria-hidden=\"true\" tabIndex=\"-1\" />",
+        "<button aria-hidden=\"true\" tabIndex={-1} />",
+        "<div aria-hidden=\"true\" tabIndex={tabIndex} />",
         "<button />",
         "<a href=\"/\" />",
     ];
@@ -125,7 +131,16 @@ fn test() {
         r#"<button aria-hidden="true" />"#,
         r#"<textarea aria-hidden="true" />"#,
         r#"<p tabIndex="0" aria-hidden="true">text</p>;"#,
+        r#"<div aria-hidden="true" tabIndex={0} />;"#,
+        r#"<span aria-hidden="true" tabIndex={2} />;"#,
     ];
 
-    Tester::new(NoAriaHiddenOnFocusable::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r#"<div aria-hidden="true" tabIndex="0" />;"#, r#"<div tabIndex="0" />;"#, None),
+        (r#"<input aria-hidden="true" />;"#, r"<input />;", None),
+        (r#"<button aria-hidden="true" />"#, r"<button />", None),
+        (r#"<p tabIndex="0" aria-hidden="true">text</p>;"#, r#"<p tabIndex="0">text</p>;"#, None),
+    ];
+
+    Tester::new(NoAriaHiddenOnFocusable::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -8,6 +8,7 @@ use oxc_span::Span;
 
 use crate::{
     context::LintContext,
+    fixer::remove_jsx_attribute,
     globals::HTML_TAG,
     rule::Rule,
     utils::{get_element_type, has_jsx_prop},
@@ -98,14 +99,18 @@ impl Rule for NoAutofocus {
                 if self.ignore_non_dom {
                     if HTML_TAG.contains(&element_type) {
                         if let oxc_ast::ast::JSXAttributeItem::Attribute(attr) = autofocus {
-                            ctx.diagnostic(NoAutofocusDiagnostic(attr.span));
+                            ctx.diagnostic_with_fix(NoAutofocusDiagnostic(attr.span), || {
+                                remove_jsx_attribute(attr, ctx)
+                            });
                         }
                     }
                     return;
                 }
 
                 if let oxc_ast::ast::JSXAttributeItem::Attribute(attr) = autofocus {
-                    ctx.diagnostic(NoAutofocusDiagnostic(attr.span));
+                    ctx.diagnostic_with_fix(NoAutofocusDiagnostic(attr.span), || {
+                        remove_jsx_attribute(attr, ctx)
+                    });
                 }
             }
         }
@@ -156,5 +161,21 @@ fn test() {
         ("<Button autoFocus />", Some(config()), Some(settings()), None),
     ];
 
-    Tester::new(NoAutofocus::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("<div autoFocus />", "<div />", None),
+        ("<div autoFocus id='foo' className='bar' />", "<div id='foo' className='bar' />", None),
+        (
+            "<div id='foo' autoFocus={true} className='bar' />",
+            "<div id='foo' className='bar' />",
+            None,
+        ),
+        (
+            "<div id='foo' className='bar' autoFocus='true'>text</div>",
+            "<div id='foo' className='bar'>text</div>",
+            None,
+        ),
+        ("<input\n  id='foo'\n  autoFocus\n/>", "<input\n  id='foo'\n/>", None),
+    ];
+
+    Tester::new(NoAutofocus::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
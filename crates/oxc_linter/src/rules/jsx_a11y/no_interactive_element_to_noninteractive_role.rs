@@ -0,0 +1,139 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_element_type, get_string_literal_prop_value, has_jsx_prop_lowercase,
+        is_interactive_element, is_non_interactive_role, parse_element_roles,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(no-interactive-element-to-noninteractive-role): Interactive elements should not be assigned non-interactive roles.")]
+#[diagnostic(
+    severity(warning),
+    help("Remove the `{1}` role from `<{2}>`, or wrap the element in a container with that role.")
+)]
+struct NoInteractiveElementToNoninteractiveRoleDiagnostic(#[label] pub Span, String, String);
+
+/// <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/no-interactive-element-to-noninteractive-role.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoInteractiveElementToNoninteractiveRole(Box<FxHashMap<String, Vec<String>>>);
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Interactive HTML elements indicate _controls_ in the user interface.
+    /// This rule prevents them from being converted to non-interactive elements
+    /// with a non-interactive ARIA role, including `presentation` and `none`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A non-interactive role strips the element of its semantics, so assistive technology
+    /// no longer announces a control the user can still focus and operate.
+    ///
+    /// ### Options
+    ///
+    /// An object mapping element names to the non-interactive roles they may be given, e.g.
+    /// `{ "button": ["presentation"] }`. No element is allowed any non-interactive role by default.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <button role="presentation" />
+    /// <input role="img" />
+    ///
+    /// // Good
+    /// <button />
+    /// <div role="presentation"><button /></div>
+    /// ```
+    NoInteractiveElementToNoninteractiveRole,
+    correctness
+);
+
+impl Rule for NoInteractiveElementToNoninteractiveRole {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        parse_element_roles(&value).map_or_else(Self::default, |roles| Self(Box::new(roles)))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+        let Some(element_type) = get_element_type(ctx, jsx_el) else { return };
+        if !is_interactive_element(&element_type, jsx_el) {
+            return;
+        }
+        let Some(role_attr) = has_jsx_prop_lowercase(jsx_el, "role") else { return };
+        let Some(roles) = get_string_literal_prop_value(role_attr) else { return };
+        // The first token is the role used by user agents, the rest are fallbacks.
+        let Some(role) = roles.split_whitespace().next() else { return };
+        if !is_non_interactive_role(role) {
+            return;
+        }
+        if self.0.get(&element_type).is_some_and(|allowed| allowed.iter().any(|r| r == role)) {
+            return;
+        }
+        ctx.diagnostic(NoInteractiveElementToNoninteractiveRoleDiagnostic(
+            role_attr.span(),
+            role.to_string(),
+            element_type,
+        ));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    fn settings() -> serde_json::Value {
+        json!({ "jsx-a11y": { "components": { "Button": "button" } } })
+    }
+
+    let pass = vec![
+        // benign
+        ("<TestComponent onClick={doFoo} />", None, None),
+        ("<Button onClick={doFoo} />", None, None),
+        ("<button />", None, None),
+        ("<button role=\"button\" />", None, None),
+        ("<button role={role} />", None, None),
+        ("<a href=\"#\" role=\"menuitem\" />", None, None),
+        ("<a role=\"listitem\" />", None, None),
+        ("<input role=\"combobox\" />", None, None),
+        ("<input type=\"hidden\" role=\"img\" />", None, None),
+        ("<select role=\"listbox\" />", None, None),
+        ("<div role=\"presentation\" />", None, None),
+        ("<main role=\"listitem\" />", None, None),
+        ("<button role=\"datepicker\" />", None, None),
+        ("<Button role=\"button\" />", None, Some(settings())),
+        // allowed by configuration
+        ("<button role=\"presentation\" />", Some(json!([{ "button": ["presentation"] }])), None),
+        ("<textarea role=\"img\" />", Some(json!([{ "textarea": ["img", "none"] }])), None),
+    ];
+
+    let fail = vec![
+        ("<button role=\"presentation\" />", None, None),
+        ("<button role=\"none\" />", None, None),
+        ("<button role=\"img\" />", None, None),
+        ("<a href=\"#\" role=\"listitem\" />", None, None),
+        ("<input role=\"img\" />", None, None),
+        ("<select role=\"article\" />", None, None),
+        ("<textarea role=\"heading\" />", None, None),
+        ("<label role=\"region\" />", None, None),
+        ("<Button role=\"presentation\" />", None, Some(settings())),
+        // configuration replaces the default allow-list
+        ("<button role=\"none\" />", Some(json!([{ "button": ["presentation"] }])), None),
+    ];
+
+    Tester::new(NoInteractiveElementToNoninteractiveRole::NAME, pass, fail)
+        .with_jsx_a11y_plugin(true)
+        .test_and_snapshot();
+}
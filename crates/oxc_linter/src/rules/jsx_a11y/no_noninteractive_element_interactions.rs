@@ -0,0 +1,250 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXOpeningElement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        get_element_type, get_string_literal_prop_value, has_jsx_prop, has_jsx_prop_lowercase,
+        is_hidden_from_screen_reader, is_interactive_element, is_interactive_role,
+        is_non_interactive_element, is_non_interactive_role, is_presentation_role,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(no-noninteractive-element-interactions): Non-interactive elements should not be assigned mouse or keyboard event listeners.")]
+#[diagnostic(
+    severity(warning),
+    help(
+        "Use an interactive element such as `<button>`, or give the element an interactive role."
+    )
+)]
+struct NoNoninteractiveElementInteractionsDiagnostic(#[label] pub Span);
+
+/// <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/no-noninteractive-element-interactions.md>
+#[derive(Debug, Clone)]
+pub struct NoNoninteractiveElementInteractions(Box<NoNoninteractiveElementInteractionsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoNoninteractiveElementInteractionsConfig {
+    handlers: Vec<String>,
+    /// Handlers allowed on a given element or role, e.g. `{ "img": ["onLoad"] }`.
+    allowed_handlers: FxHashMap<String, Vec<String>>,
+}
+
+impl std::ops::Deref for NoNoninteractiveElementInteractions {
+    type Target = NoNoninteractiveElementInteractionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+const DEFAULT_HANDLERS: [&str; 8] = [
+    "onClick",
+    "onError",
+    "onLoad",
+    "onMouseDown",
+    "onMouseUp",
+    "onKeyPress",
+    "onKeyDown",
+    "onKeyUp",
+];
+
+impl Default for NoNoninteractiveElementInteractions {
+    fn default() -> Self {
+        Self(Box::new(NoNoninteractiveElementInteractionsConfig {
+            handlers: DEFAULT_HANDLERS.into_iter().map(String::from).collect(),
+            allowed_handlers: FxHashMap::default(),
+        }))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that non-interactive elements, such as `<main>`, `<li>` or `<div role="article">`,
+    /// are not assigned mouse or keyboard event handlers.
+    ///
+    /// Elements that are hidden from screen readers, `contentEditable`, or explicitly marked
+    /// with `role="presentation"` or `role="none"` are not checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Non-interactive elements describe content and containers to assistive technology, which
+    /// won't announce them as something that can be operated. Handlers on them are usually
+    /// unreachable for keyboard and screen reader users.
+    ///
+    /// ### Options
+    ///
+    /// * `handlers`: the event handlers to check, defaults to `onClick`, `onError`, `onLoad`,
+    ///   `onMouseDown`, `onMouseUp`, `onKeyPress`, `onKeyDown` and `onKeyUp`.
+    /// * Any other key is an element name or role mapped to handlers allowed on it, e.g.
+    ///   `{ "img": ["onError", "onLoad"] }`.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <li onClick={() => void 0} />
+    /// <div role="listitem" onClick={() => void 0} />
+    ///
+    /// // Good
+    /// <button onClick={() => void 0} />
+    /// <li role="presentation" onClick={() => void 0} />
+    /// ```
+    NoNoninteractiveElementInteractions,
+    correctness
+);
+
+impl Rule for NoNoninteractiveElementInteractions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0).and_then(serde_json::Value::as_object) else {
+            return Self::default();
+        };
+        let strings = |value: &serde_json::Value| -> Vec<String> {
+            value
+                .as_array()
+                .map(|values| values.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                .unwrap_or_default()
+        };
+        let mut rule = Self::default();
+        for (key, value) in config {
+            if key == "handlers" {
+                rule.0.handlers = strings(value);
+            } else {
+                rule.0.allowed_handlers.insert(key.clone(), strings(value));
+            }
+        }
+        rule
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+        let Some(element_type) = get_element_type(ctx, jsx_el) else { return };
+        if !HTML_TAG.contains(&element_type) {
+            return;
+        }
+
+        // The first token is the role used by user agents, the rest are fallbacks.
+        let role = has_jsx_prop_lowercase(jsx_el, "role")
+            .and_then(get_string_literal_prop_value)
+            .and_then(|role| role.split_whitespace().next());
+        let allowed = |handler: &str| {
+            [Some(element_type.as_str()), role].into_iter().flatten().any(|key| {
+                self.allowed_handlers
+                    .get(key)
+                    .is_some_and(|allowed| allowed.iter().any(|h| h == handler))
+            })
+        };
+        if !self
+            .handlers
+            .iter()
+            .any(|handler| has_jsx_prop(jsx_el, handler).is_some() && !allowed(handler))
+        {
+            return;
+        }
+
+        if is_content_editable(jsx_el)
+            || is_hidden_from_screen_reader(ctx, jsx_el)
+            || is_presentation_role(jsx_el)
+        {
+            return;
+        }
+        if is_interactive_element(&element_type, jsx_el) || role.is_some_and(is_interactive_role) {
+            return;
+        }
+        if !is_non_interactive_element(&element_type, jsx_el)
+            && !role.is_some_and(is_non_interactive_role)
+        {
+            return;
+        }
+
+        ctx.diagnostic(NoNoninteractiveElementInteractionsDiagnostic(jsx_el.span));
+    }
+}
+
+fn is_content_editable(jsx_el: &JSXOpeningElement) -> bool {
+    match has_jsx_prop(jsx_el, "contentEditable") {
+        Some(JSXAttributeItem::Attribute(attr)) if attr.value.is_none() => true,
+        Some(item) => get_string_literal_prop_value(item) == Some("true"),
+        None => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    fn settings() -> serde_json::Value {
+        json!({ "jsx-a11y": { "components": { "Item": "li" } } })
+    }
+
+    let pass = vec![
+        ("<TestComponent onClick={doFoo} />", None, None),
+        ("<Button onClick={doFoo} />", None, None),
+        ("<Item onClick={doFoo} />", None, None),
+        ("<div onClick={() => void 0} />", None, None),
+        ("<button onClick={() => void 0} />", None, None),
+        ("<a href=\"#\" onClick={() => void 0} />", None, None),
+        ("<input onKeyDown={() => void 0} />", None, None),
+        ("<input type=\"text\" onClick={() => void 0} />", None, None),
+        ("<select onClick={() => void 0} />", None, None),
+        ("<textarea onKeyUp={() => void 0} />", None, None),
+        ("<button type=\"submit\" onKeyDown={() => void 0} />", None, None),
+        ("<li onCopy={() => void 0} />", None, None),
+        ("<li className=\"foo\" />", None, None),
+        ("<li role=\"button\" onClick={() => void 0} />", None, None),
+        ("<li role=\"presentation\" onClick={() => void 0} />", None, None),
+        ("<li role=\"none\" onClick={() => void 0} />", None, None),
+        ("<main role=\"presentation\" onKeyDown={() => void 0} />", None, None),
+        ("<li aria-hidden onClick={() => void 0} />", None, None),
+        ("<li contentEditable onClick={() => void 0} />", None, None),
+        ("<p contentEditable=\"true\" onKeyDown={() => void 0} />", None, None),
+        ("<div role=\"article\" onCopy={() => void 0} />", None, None),
+        ("<img onLoad={() => void 0} />", Some(json!([{ "img": ["onLoad"] }])), None),
+        (
+            "<div role=\"alert\" onKeyUp={() => void 0} />",
+            Some(json!([{ "alert": ["onKeyUp", "onKeyDown"] }])),
+            None,
+        ),
+        ("<li onMouseDown={() => void 0} />", Some(json!([{ "handlers": ["onClick"] }])), None),
+    ];
+
+    let fail = vec![
+        ("<li onClick={() => void 0} />", None, None),
+        ("<main onKeyDown={() => void 0} />", None, None),
+        ("<section onMouseDown={() => void 0} />", None, None),
+        ("<img onError={() => void 0} />", None, None),
+        ("<fieldset onClick={() => void 0} />", None, None),
+        ("<legend onClick={() => void 0} />", None, None),
+        ("<output onKeyDown={() => void 0} />", None, None),
+        ("<li role=\"presentation-ish\" onClick={() => void 0} />", None, None),
+        ("<div role=\"listitem\" onClick={() => void 0} />", None, None),
+        ("<div role=\"article\" onKeyUp={() => void 0} />", None, None),
+        ("<li aria-hidden={false} onClick={() => void 0} />", None, None),
+        ("<li contentEditable=\"false\" onClick={() => void 0} />", None, None),
+        ("<Item onClick={doFoo} />", None, Some(settings())),
+        (
+            "<img onLoad={() => void 0} onClick={() => void 0} />",
+            Some(json!([{ "img": ["onLoad"] }])),
+            None,
+        ),
+        ("<li onClick={() => void 0} />", Some(json!([{ "handlers": ["onClick"] }])), None),
+    ];
+
+    Tester::new(NoNoninteractiveElementInteractions::NAME, pass, fail)
+        .with_jsx_a11y_plugin(true)
+        .test_and_snapshot();
+}
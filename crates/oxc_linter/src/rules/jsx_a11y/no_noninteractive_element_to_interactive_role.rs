@@ -0,0 +1,183 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_element_type, get_string_literal_prop_value, has_jsx_prop_lowercase,
+        is_interactive_role, is_non_interactive_element, parse_element_roles,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(no-noninteractive-element-to-interactive-role): Non-interactive elements should not be assigned interactive roles.")]
+#[diagnostic(
+    severity(warning),
+    help("Use an interactive element such as `<button>` instead of assigning the `{1}` role to `<{2}>`.")
+)]
+struct NoNoninteractiveElementToInteractiveRoleDiagnostic(#[label] pub Span, String, String);
+
+/// <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/no-noninteractive-element-to-interactive-role.md>
+#[derive(Debug, Clone)]
+pub struct NoNoninteractiveElementToInteractiveRole(Box<FxHashMap<String, Vec<String>>>);
+
+impl Default for NoNoninteractiveElementToInteractiveRole {
+    fn default() -> Self {
+        let allowed: [(&str, &[&str]); 6] = [
+            ("ul", &["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"]),
+            ("ol", &["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"]),
+            (
+                "li",
+                &[
+                    "menuitem",
+                    "menuitemradio",
+                    "menuitemcheckbox",
+                    "option",
+                    "row",
+                    "tab",
+                    "treeitem",
+                ],
+            ),
+            ("table", &["grid"]),
+            ("td", &["gridcell"]),
+            ("fieldset", &["radiogroup", "presentation"]),
+        ];
+        Self(Box::new(
+            allowed
+                .into_iter()
+                .map(|(element, roles)| {
+                    (element.to_string(), roles.iter().map(ToString::to_string).collect())
+                })
+                .collect(),
+        ))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Non-interactive HTML elements indicate _content_ and _containers_ in the user interface.
+    /// This rule prevents them from being converted into interactive controls with an
+    /// interactive ARIA role.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Interactive roles promise keyboard and pointer behaviour that non-interactive elements
+    /// don't provide, so assistive technology users are told a control exists that doesn't work.
+    ///
+    /// ### Options
+    ///
+    /// An object mapping element names to the interactive roles they may be given, e.g.
+    /// `{ "ul": ["listbox"], "li": ["option"] }`. When omitted, lists may become list-like
+    /// widgets (`listbox`, `menu`, `tablist`, ...), `li` their items, `table` a `grid`,
+    /// `td` a `gridcell` and `fieldset` a `radiogroup`.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <main role="button" />
+    /// <h1 role="checkbox" />
+    ///
+    /// // Good
+    /// <div role="button" />
+    /// <ul role="menu"><li role="menuitem" /></ul>
+    /// ```
+    NoNoninteractiveElementToInteractiveRole,
+    correctness
+);
+
+impl Rule for NoNoninteractiveElementToInteractiveRole {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        parse_element_roles(&value).map_or_else(Self::default, |roles| Self(Box::new(roles)))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+        let Some(element_type) = get_element_type(ctx, jsx_el) else { return };
+        if !is_non_interactive_element(&element_type, jsx_el) {
+            return;
+        }
+        let Some(role_attr) = has_jsx_prop_lowercase(jsx_el, "role") else { return };
+        let Some(roles) = get_string_literal_prop_value(role_attr) else { return };
+        // The first token is the role used by user agents, the rest are fallbacks.
+        let Some(role) = roles.split_whitespace().next() else { return };
+        if !is_interactive_role(role) {
+            return;
+        }
+        if self.0.get(&element_type).is_some_and(|allowed| allowed.iter().any(|r| r == role)) {
+            return;
+        }
+        ctx.diagnostic(NoNoninteractiveElementToInteractiveRoleDiagnostic(
+            role_attr.span(),
+            role.to_string(),
+            element_type,
+        ));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    fn settings() -> serde_json::Value {
+        json!({ "jsx-a11y": { "components": { "Article": "article" } } })
+    }
+
+    let pass = vec![
+        // benign
+        ("<TestComponent onClick={doFoo} />", None, None),
+        ("<Button onClick={doFoo} />", None, None),
+        ("<main />", None, None),
+        ("<div role=\"button\" />", None, None),
+        ("<span role=\"checkbox\" />", None, None),
+        ("<button role=\"button\" />", None, None),
+        ("<input role=\"combobox\" />", None, None),
+        ("<a href=\"#\" role=\"menuitem\" />", None, None),
+        ("<main role={role} />", None, None),
+        ("<main role=\"banner\" />", None, None),
+        ("<li role=\"presentation\" />", None, None),
+        ("<article role=\"listitem\" />", None, None),
+        ("<img usemap=\"#map\" role=\"button\" />", None, None),
+        ("<Article />", None, Some(settings())),
+        // allowed by default
+        ("<ul role=\"menu\"><li role=\"menuitem\" /></ul>", None, None),
+        ("<ol role=\"listbox\"><li role=\"option\" /></ol>", None, None),
+        ("<ul role=\"tablist\"><li role=\"tab\" /></ul>", None, None),
+        ("<table role=\"grid\"><tr><td role=\"gridcell\" /></tr></table>", None, None),
+        ("<fieldset role=\"radiogroup\" />", None, None),
+        // allowed by configuration
+        ("<main role=\"button\" />", Some(json!([{ "main": ["button"] }])), None),
+        ("<h1 role=\"button checkbox\" />", Some(json!([{ "h1": ["button"] }])), None),
+    ];
+
+    let fail = vec![
+        ("<main role=\"button\" />", None, None),
+        ("<article role=\"button\" />", None, None),
+        ("<aside role=\"menuitem\" />", None, None),
+        ("<h1 role=\"checkbox\" />", None, None),
+        ("<h2 role=\"link\" />", None, None),
+        ("<img role=\"slider\" />", None, None),
+        ("<li role=\"button\" />", None, None),
+        ("<nav role=\"menuitemradio\" />", None, None),
+        ("<p role=\"textbox\" />", None, None),
+        ("<section role=\"switch\" />", None, None),
+        ("<ul role=\"button\" />", None, None),
+        ("<Article role=\"button\" />", None, Some(settings())),
+        // configuration replaces the default allow-list
+        ("<ul role=\"menu\" />", Some(json!([{ "ol": ["menu"] }])), None),
+        ("<main role=\"link\" />", Some(json!([{ "main": ["button"] }])), None),
+    ];
+
+    Tester::new(NoNoninteractiveElementToInteractiveRole::NAME, pass, fail)
+        .with_jsx_a11y_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,208 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeValue},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        get_element_type, get_prop_value, get_string_literal_prop_value, has_jsx_prop_lowercase,
+        is_interactive_element, is_interactive_role, parse_jsx_value,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(no-noninteractive-tabindex): `tabIndex` should only be declared on interactive elements.")]
+#[diagnostic(
+    severity(warning),
+    help("Remove the `tabIndex` attribute, or give the element an interactive role.")
+)]
+struct NoNoninteractiveTabindexDiagnostic(#[label] pub Span);
+
+/// <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/no-noninteractive-tabindex.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoNoninteractiveTabindex(Box<NoNoninteractiveTabindexConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNoninteractiveTabindexConfig {
+    tags: Vec<String>,
+    roles: Vec<String>,
+    allow_expression_values: bool,
+}
+
+impl std::ops::Deref for NoNoninteractiveTabindex {
+    type Target = NoNoninteractiveTabindexConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that `tabIndex` is not assigned a non-negative value on non-interactive
+    /// elements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Tab key navigation should be limited to elements on the page that can be interacted
+    /// with. Making content focusable only adds stops for keyboard users without anything to
+    /// operate.
+    ///
+    /// ### Options
+    ///
+    /// * `tags`: element names that may be focusable, e.g. `["article"]`.
+    /// * `roles`: roles that may be focusable, e.g. `["tabpanel"]`.
+    /// * `allowExpressionValues`: allow elements whose `role` is an expression, such as
+    ///   `role={isButton ? "button" : "link"}`, since the role can't be checked statically.
+    ///
+    /// `tabIndex` values that can't be evaluated statically (`tabIndex={index}`) are always
+    /// ignored. Like upstream, `allowExpressionValues` only applies to `role`, it doesn't make
+    /// dynamic `tabIndex` values reported.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <div tabIndex="0" />
+    /// <article tabIndex={0} />
+    ///
+    /// // Good
+    /// <div role="button" tabIndex="0" />
+    /// <div tabIndex="-1" />
+    /// <button />
+    /// ```
+    NoNoninteractiveTabindex,
+    correctness
+);
+
+impl Rule for NoNoninteractiveTabindex {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        let strings = |key: &str| {
+            config
+                .get(key)
+                .and_then(serde_json::Value::as_array)
+                .map(|values| values.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                .unwrap_or_default()
+        };
+        Self(Box::new(NoNoninteractiveTabindexConfig {
+            tags: strings("tags"),
+            roles: strings("roles"),
+            allow_expression_values: config
+                .get("allowExpressionValues")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+        let Some(JSXAttributeItem::Attribute(tab_index_attr)) =
+            has_jsx_prop_lowercase(jsx_el, "tabIndex")
+        else {
+            return;
+        };
+        let Some(tab_index) = tab_index_attr.value.as_ref().and_then(|v| parse_jsx_value(v).ok())
+        else {
+            return;
+        };
+        if tab_index < 0.0 {
+            return;
+        }
+
+        let Some(element_type) = get_element_type(ctx, jsx_el) else { return };
+        if !HTML_TAG.contains(&element_type) || self.tags.contains(&element_type) {
+            return;
+        }
+
+        if let Some(role_attr) = has_jsx_prop_lowercase(jsx_el, "role") {
+            if self.allow_expression_values
+                && matches!(
+                    get_prop_value(role_attr),
+                    Some(JSXAttributeValue::ExpressionContainer(_))
+                )
+            {
+                return;
+            }
+            // The first token is the role used by user agents, the rest are fallbacks.
+            if let Some(role) =
+                get_string_literal_prop_value(role_attr).and_then(|r| r.split_whitespace().next())
+            {
+                if is_interactive_role(role) || self.roles.iter().any(|r| r == role) {
+                    return;
+                }
+            }
+        }
+        if is_interactive_element(&element_type, jsx_el) {
+            return;
+        }
+
+        ctx.diagnostic(NoNoninteractiveTabindexDiagnostic(tab_index_attr.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    fn settings() -> serde_json::Value {
+        json!({ "jsx-a11y": { "components": { "Article": "article" } } })
+    }
+
+    let pass = vec![
+        ("<MyButton tabIndex={0} />", None, None),
+        ("<button />", None, None),
+        ("<button tabIndex=\"0\" />", None, None),
+        ("<button tabIndex={0} />", None, None),
+        ("<div />", None, None),
+        ("<div tabIndex=\"-1\" />", None, None),
+        ("<div tabIndex={-1} />", None, None),
+        ("<div tabIndex={tabIndex} />", None, None),
+        ("<div role=\"button\" tabIndex=\"0\" />", None, None),
+        ("<div role=\"article\" tabIndex=\"-1\" />", None, None),
+        ("<article tabIndex=\"-1\" />", None, None),
+        ("<a href=\"#\" tabIndex={2} />", None, None),
+        ("<Article tabIndex={-1} />", None, Some(settings())),
+        ("<article tabIndex=\"0\" />", Some(json!([{ "tags": ["article"] }])), None),
+        (
+            "<div role=\"tabpanel\" tabIndex=\"0\" />",
+            Some(json!([{ "roles": ["tabpanel"] }])),
+            None,
+        ),
+        (
+            "<div role={isButton ? \"button\" : \"link\"} tabIndex=\"0\" />",
+            Some(json!([{ "allowExpressionValues": true }])),
+            None,
+        ),
+        ("<div tabIndex={tabIndex} />", Some(json!([{ "allowExpressionValues": true }])), None),
+        ("<div tabIndex={tabIndex} />", Some(json!([{ "allowExpressionValues": false }])), None),
+    ];
+
+    let fail = vec![
+        ("<div tabIndex=\"0\" />", None, None),
+        ("<div tabIndex={0} />", None, None),
+        ("<div tabIndex={2} />", None, None),
+        ("<div role=\"article\" tabIndex=\"0\" />", None, None),
+        ("<article tabIndex=\"0\" />", None, None),
+        ("<article tabIndex={0} />", None, None),
+        ("<Article tabIndex={0} />", None, Some(settings())),
+        ("<div role=\"tabpanel\" tabIndex=\"0\" />", None, None),
+        ("<div role={isButton ? \"button\" : \"link\"} tabIndex=\"0\" />", None, None),
+        ("<article tabIndex=\"0\" />", Some(json!([{ "tags": ["section"] }])), None),
+    ];
+
+    Tester::new(NoNoninteractiveTabindex::NAME, pass, fail)
+        .with_jsx_a11y_plugin(true)
+        .test_and_snapshot();
+}
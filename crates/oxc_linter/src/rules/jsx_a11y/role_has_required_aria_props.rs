@@ -1,4 +1,4 @@
-use crate::{context::LintContext, rule::Rule, utils::has_jsx_prop_lowercase, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, utils::has_jsx_prop_lowercase, AstNode};
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeValue},
     AstKind,
@@ -35,6 +35,10 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// Certain ARIA roles require specific attributes to express necessary semantics for assistive technology.
     ///
+    /// Missing state attributes with a safe default, such as `aria-checked="false"` for a
+    /// `checkbox`, are inserted after the `role` attribute by the fixer. Values that depend on
+    /// the widget, such as `aria-valuenow`, are left to the author.
+    ///
     /// ### Example
     /// ```javascript
     /// // Bad
@@ -58,6 +62,13 @@ static ROLE_TO_REQUIRED_ARIA_PROPS: phf::Map<&'static str, phf::Set<&'static str
     "option" => phf_set!{"aria-selected"},
 };
 
+/// Required props whose ARIA default value is safe to insert.
+static REQUIRED_ARIA_PROP_DEFAULTS: phf::Map<&'static str, &'static str> = phf_map! {
+    "aria-checked" => "false",
+    "aria-selected" => "false",
+    "aria-expanded" => "false",
+};
+
 impl Rule for RoleHasRequiredAriaProps {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
@@ -68,13 +79,27 @@ impl Rule for RoleHasRequiredAriaProps {
             for role in roles {
                 if let Some(props) = ROLE_TO_REQUIRED_ARIA_PROPS.get(role) {
                     for prop in props {
-                        if has_jsx_prop_lowercase(jsx_el, prop).is_none() {
-                            ctx.diagnostic(RoleHasRequiredAriaPropsDiagnostic {
-                                span: attr.span,
-                                role: role.into(),
-                                props: (*prop).into(),
-                            });
+                        if has_jsx_prop_lowercase(jsx_el, prop).is_some() {
+                            continue;
                         }
+                        let diagnostic = RoleHasRequiredAriaPropsDiagnostic {
+                            span: attr.span,
+                            role: role.into(),
+                            props: (*prop).into(),
+                        };
+                        let Some(default) = REQUIRED_ARIA_PROP_DEFAULTS.get(prop) else {
+                            ctx.diagnostic(diagnostic);
+                            continue;
+                        };
+                        ctx.diagnostic_with_fix(diagnostic, || {
+                            // Quote the value like the `role` value.
+                            let quote = &role_values.span.source_text(ctx.source_text())[..1];
+                            let end = attr.span.end;
+                            Fix::new(
+                                format!(" {prop}={quote}{default}{quote}"),
+                                Span::new(end, end),
+                            )
+                        });
                     }
                 }
             }
@@ -132,5 +157,22 @@ fn test() {
         ("<MyComponent role='combobox' />", None, Some(settings()), None),
     ];
 
-    Tester::new(RoleHasRequiredAriaProps::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("<div role='checkbox' />", "<div role='checkbox' aria-checked='false' />", None),
+        (
+            "<span role=\"radio\" tabindex=\"0\"></span>",
+            "<span role=\"radio\" aria-checked=\"false\" tabindex=\"0\"></span>",
+            None,
+        ),
+        ("<div role='tab' />", "<div role='tab' aria-selected='false' />", None),
+        (
+            "<div role='combobox' aria-controls='list' />",
+            "<div role='combobox' aria-expanded='false' aria-controls='list' />",
+            None,
+        ),
+        ("<div role='heading' />", "<div role='heading' />", None),
+        ("<div role='slider' />", "<div role='slider' />", None),
+    ];
+
+    Tester::new(RoleHasRequiredAriaProps::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
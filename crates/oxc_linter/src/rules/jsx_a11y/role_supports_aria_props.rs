@@ -1556,10 +1556,14 @@ fn test() {
         (r"<datalist aria-expanded />", None, None, None),
         (r#"<div role="heading" aria-level />"#, None, None, None),
         (r#"<div role="heading" aria-level="1" />"#, None, None, None),
+        (r#"<div role="checkbox" aria-checked="true" />"#, None, None, None),
+        (r#"<span role="link" aria-expanded="false" />"#, None, None, None),
     ];
 
     let fail = vec![
         (r#"<a href="/" aria-checked />"#, None, None, None),
+        (r#"<div role="link" aria-checked />"#, None, None, None),
+        (r#"<span role="button" aria-required />"#, None, None, None),
         (r#"<area href="/" aria-checked />"#, None, None, None),
         (r#"<link href="/" aria-checked />"#, None, None, None),
         (r#"<img alt="foobar" aria-checked />"#, None, None, None),
@@ -8,6 +8,7 @@ use oxc_span::Span;
 
 use crate::{
     context::LintContext,
+    fixer::remove_jsx_attribute,
     globals::HTML_TAG,
     rule::Rule,
     utils::{get_element_type, has_jsx_prop_lowercase},
@@ -75,7 +76,9 @@ impl Rule for Scope {
             return;
         }
 
-        ctx.diagnostic(ScopeDiagnostic(scope_attribute.span));
+        ctx.diagnostic_with_fix(ScopeDiagnostic(scope_attribute.span), || {
+            remove_jsx_attribute(scope_attribute, ctx)
+        });
     }
 }
 
@@ -108,5 +111,14 @@ fn test() {
     let fail =
         vec![(r"<div scope />", None, None), (r"<Foo scope='bar' />;", None, Some(settings()))];
 
-    Tester::new(Scope::NAME, pass, fail).with_jsx_a11y_plugin(true).test_and_snapshot();
+    let fix = vec![
+        (r"<div scope />", r"<div />", None),
+        (r"<div scope='row' id='foo' />", r"<div id='foo' />", None),
+        (r"<div id='foo' scope='row'></div>", r"<div id='foo'></div>", None),
+    ];
+
+    Tester::new(Scope::NAME, pass, fail)
+        .with_jsx_a11y_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -96,6 +96,7 @@ fn test() {
         (r#"<div tabIndex={"1"} />"#, None),
         (r"<div tabIndex={`1`} />", None),
         (r"<div tabIndex={1.589} />", None),
+        (r"<div tabIndex={2} />", None),
     ];
 
     Tester::new(TabindexNoPositive::NAME, pass, fail).test_and_snapshot();
@@ -1,24 +1,31 @@
 use std::{
     collections::HashMap,
     fs,
-    path::Path,
+    path::{Path, PathBuf},
     rc::Rc,
     sync::{Arc, Condvar, Mutex},
 };
 
 use dashmap::DashMap;
-use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
-use rustc_hash::FxHashSet;
+use once_cell::sync::OnceCell;
+use rayon::{
+    iter::ParallelBridge,
+    prelude::{IntoParallelRefIterator, ParallelIterator},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError};
 use oxc_parser::Parser;
-use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_resolver::Resolver;
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_span::SourceType;
+use oxc_syntax::module_record::{ExportImportName, ImportImportName, RequestedModule};
 
 use crate::{
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
+    rules::RuleEnum,
+    utils::canonicalize_path,
     Fixer, LintContext, Linter, Message,
 };
 
@@ -29,13 +36,13 @@ pub struct LintService {
 
 impl LintService {
     pub fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
-        let runtime = Arc::new(Runtime::new(cwd, paths, linter));
+        let runtime = Arc::new(Runtime::new(cwd, paths, Arc::new(linter)));
         Self { runtime }
     }
 
     #[cfg(test)]
     pub(crate) fn from_linter(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
-        let runtime = Arc::new(Runtime::new(cwd, paths, linter));
+        let runtime = Arc::new(Runtime::new(cwd, paths, Arc::new(linter)));
         Self { runtime }
     }
 
@@ -49,11 +56,10 @@ impl LintService {
 
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
-        self.runtime
-            .paths
-            .iter()
-            .par_bridge()
-            .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        self.runtime.collect_export_usages(tx_error);
+        self.runtime.paths.iter().par_bridge().for_each_with(&self.runtime, |runtime, path| {
+            runtime.process_path(path, true, tx_error);
+        });
         tx_error.send(None).unwrap();
     }
 
@@ -66,18 +72,24 @@ impl LintService {
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
+        self.runtime.collect_export_usages(tx_error);
         self.runtime
             .paths
             .iter()
             .flat_map(|path| {
                 let source_type = SourceType::from_path(path).unwrap();
-                self.runtime.init_cache_state(path);
+                let preloaded = self.runtime.take_preloaded(path);
+                if !preloaded {
+                    self.runtime.init_cache_state(path);
+                }
                 self.runtime.process_source(
                     path,
                     allocator,
                     source_text,
                     source_type,
                     check_syntax_errors,
+                    true,
+                    preloaded,
                     tx_error,
                 )
             })
@@ -102,36 +114,116 @@ enum CacheStateEntry {
     PendingStore(usize),
 }
 
-/// Keyed by canonicalized path
+/// Keyed by canonicalized path.
+///
+/// Every module is loaded once per lint run, no matter how many files import it, and its
+/// record is shared through `ModuleRecord::loaded_modules` so that rules such as
+/// `import/no-cycle` traverse the same graph from every linted file. Files preloaded by
+/// `collect_export_usages` are parsed again to be linted, but keep the record they were
+/// loaded with.
 type ModuleMap = DashMap<Box<Path>, Arc<ModuleRecord>>;
 
+/// Exports used by the `src` files of `import/no-unused-modules`, keyed by the resolved path of
+/// the exporting module.
+pub type ExportUsages = FxHashMap<PathBuf, ExportUsage>;
+
+#[derive(Debug, Default, Clone)]
+pub struct ExportUsage {
+    /// Imported as a namespace, re-exported with `export *`, or imported dynamically.
+    all: bool,
+    names: FxHashSet<String>,
+}
+
+impl ExportUsage {
+    pub fn is_used(&self, name: &str) -> bool {
+        self.all || self.names.contains(name)
+    }
+}
+
+#[derive(Clone)]
+enum UsedExport {
+    All,
+    Name(String),
+}
+
 pub struct Runtime {
     cwd: Box<Path>,
     /// All paths to lint
     paths: FxHashSet<Box<Path>>,
-    linter: Linter,
-    resolver: Resolver,
+    linter: Arc<Linter>,
+    /// Configured from `settings["import/resolver"]`, shared by the module graph and the rules.
+    resolver: Arc<Resolver>,
     module_map: ModuleMap,
     cache_state: CacheState,
+    /// Collected from the module graph before linting starts, once per lint run.
+    export_usages: OnceCell<Arc<ExportUsages>>,
+    /// The exports each module loaded by `collect_export_usages` uses, keyed by its path.
+    ///
+    /// Collected while its source is alive, as the strings of a module record point into it.
+    used_exports: DashMap<Box<Path>, Vec<(PathBuf, UsedExport)>>,
+    /// Canonicalized paths to lint whose module record was loaded before linting started.
+    ///
+    /// They are parsed again to be linted, but keep the record other records already point to.
+    preloaded_paths: Mutex<FxHashSet<Box<Path>>>,
 }
 
 impl Runtime {
-    fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
+    fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Arc<Linter>) -> Self {
+        let resolver = Resolver::new(linter.settings().import_resolver.resolve_options(&cwd));
         Self {
             cwd,
             paths: paths.iter().cloned().collect(),
             linter,
-            resolver: Self::resolver(),
+            resolver: Arc::new(resolver),
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            export_usages: OnceCell::new(),
+            used_exports: DashMap::default(),
+            preloaded_paths: Mutex::default(),
         }
     }
 
-    fn resolver() -> Resolver {
-        Resolver::new(ResolveOptions {
-            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
-            ..ResolveOptions::default()
-        })
+    /// Loads the module records of the `src` files of `import/no-unused-modules` into the
+    /// module map, without linting them, and collects the exports they use.
+    ///
+    /// Every file must be checked against the imports of all `src` files, so this runs before
+    /// any file is linted.
+    fn collect_export_usages(&self, tx_error: &DiagnosticSender) {
+        if !self.linter.options().import_plugin {
+            return;
+        }
+        let Some(src) = self.linter.rules.iter().find_map(|(_, rule)| match rule {
+            RuleEnum::NoUnusedModules(rule) => rule.src(),
+            _ => None,
+        }) else {
+            return;
+        };
+        let mut files = vec![];
+        for path in src {
+            collect_files(path, &mut files);
+        }
+        files.par_iter().for_each_with(tx_error, |tx_error, path| {
+            self.process_path(path, false, tx_error);
+        });
+
+        let mut usages = ExportUsages::default();
+        let used = files.iter().filter_map(|path| self.used_exports.get(path.as_path()));
+        for (path, used_export) in used.flat_map(|used| used.value().clone()) {
+            let usage = usages.entry(path).or_default();
+            match used_export {
+                UsedExport::All => usage.all = true,
+                UsedExport::Name(name) => {
+                    usage.names.insert(name);
+                }
+            }
+        }
+        *self.preloaded_paths.lock().unwrap() = self
+            .paths
+            .iter()
+            .map(|path| canonicalize_path(path).into_boxed_path())
+            .filter(|path| self.module_map.contains_key(path))
+            .collect();
+        let _ = self.export_usages.set(Arc::new(usages));
     }
 
     fn get_source_type_and_text(
@@ -159,8 +251,11 @@ impl Runtime {
         Some(Ok((source_type, source_text)))
     }
 
-    fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
-        if self.init_cache_state(path) {
+    /// Loads the module record of `path` and its dependencies, and lints `path` if `lint` is
+    /// set and it is one of the paths to lint.
+    fn process_path(&self, path: &Path, lint: bool, tx_error: &DiagnosticSender) {
+        let preloaded = lint && self.paths.contains(path) && self.take_preloaded(path);
+        if !preloaded && self.init_cache_state(path) {
             return;
         }
         let Some(ext) = path.extension().and_then(std::ffi::OsStr::to_str) else {
@@ -186,8 +281,19 @@ impl Runtime {
 
         for JavaScriptSource { source_text, source_type, .. } in sources {
             let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+            let mut messages = self.process_source(
+                path,
+                &allocator,
+                source_text,
+                source_type,
+                true,
+                lint,
+                preloaded,
+                tx_error,
+            );
+            if !lint {
+                continue;
+            }
 
             // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
             if !is_processed_by_partial_loader && self.linter.options().fix {
@@ -213,6 +319,8 @@ impl Runtime {
         source_text: &'a str,
         source_type: SourceType,
         check_syntax_errors: bool,
+        lint: bool,
+        preloaded: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
         let ret = Parser::new(allocator, source_text, source_type)
@@ -230,13 +338,18 @@ impl Runtime {
         let semantic_builder = SemanticBuilder::new(source_text, source_type)
             .with_trivias(ret.trivias)
             .with_check_syntax_error(check_syntax_errors)
+            .with_dynamic_imports(self.linter.options().import_plugin)
             .build_module_record(path.to_path_buf(), program);
         let module_record = semantic_builder.module_record();
 
         if self.linter.options().import_plugin {
-            self.module_map
-                .insert(path.to_path_buf().into_boxed_path(), Arc::clone(&module_record));
-            self.update_cache_state(path);
+            // A preloaded module keeps its record in the module map, as other records have
+            // already loaded it.
+            if !preloaded {
+                self.module_map
+                    .insert(path.to_path_buf().into_boxed_path(), Arc::clone(&module_record));
+                self.update_cache_state(path);
+            }
 
             // Retrieve all dependency modules from this module.
             let dir = path.parent().unwrap();
@@ -244,13 +357,13 @@ impl Runtime {
                 .requested_modules
                 .keys()
                 .par_bridge()
-                .map_with(&self.resolver, |resolver, specifier| {
+                .map_with(&*self.resolver, |resolver, specifier| {
                     resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
                 })
                 .flatten()
                 .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
                     let path = resolution.path();
-                    self.process_path(path, tx_error);
+                    self.process_path(path, lint, tx_error);
                     if let Some(target_module_record) = self.module_map.get(path) {
                         module_record
                             .loaded_modules
@@ -258,6 +371,13 @@ impl Runtime {
                     }
                 });
 
+            // Modules are only loaded without linting them by `collect_export_usages`.
+            if !lint {
+                self.used_exports
+                    .insert(path.to_path_buf().into_boxed_path(), used_exports(&module_record));
+                return vec![];
+            }
+
             // Stop if the current module is not marked for lint.
             if !self.paths.contains(path) {
                 return vec![];
@@ -270,11 +390,21 @@ impl Runtime {
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
-        let lint_ctx =
-            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+        let mut lint_ctx =
+            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic))
+                .with_resolver(&self.resolver);
+        if let Some(export_usages) = self.export_usages.get() {
+            lint_ctx = lint_ctx.with_export_usages(export_usages);
+        }
         self.linter.run(lint_ctx)
     }
 
+    /// Whether `path` was preloaded by `collect_export_usages` and has not been linted yet.
+    fn take_preloaded(&self, path: &Path) -> bool {
+        let mut preloaded_paths = self.preloaded_paths.lock().unwrap();
+        !preloaded_paths.is_empty() && preloaded_paths.remove(canonicalize_path(path).as_path())
+    }
+
     fn init_cache_state(&self, path: &Path) -> bool {
         if !self.linter.options().import_plugin {
             return false;
@@ -330,3 +460,150 @@ impl Runtime {
         }
     }
 }
+
+/// Source files at `path`, recursively for directories, skipping `node_modules`.
+pub fn collect_files(path: &Path, files: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        if SourceType::from_path(path).is_ok() {
+            files.push(path.to_path_buf());
+        }
+        return;
+    }
+    if path.file_name().is_some_and(|name| name == "node_modules") {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(path) else { return };
+    for entry in entries.flatten() {
+        collect_files(&entry.path(), files);
+    }
+}
+
+/// The exports `module_record` uses from its loaded modules, keyed by their resolved paths.
+fn used_exports(module_record: &ModuleRecord) -> Vec<(PathBuf, UsedExport)> {
+    // Type-only imports count as uses too, like in eslint-plugin-import.
+    let imports =
+        module_record.import_entries.iter().chain(&module_record.type_import_entries).map(
+            |entry| {
+                let used_export = match &entry.import_name {
+                    ImportImportName::Name(name) => UsedExport::Name(name.name().to_string()),
+                    ImportImportName::Default(_) => UsedExport::Name("default".to_string()),
+                    ImportImportName::NamespaceObject => UsedExport::All,
+                };
+                (entry.module_request.name(), used_export)
+            },
+        );
+    let re_exports = module_record
+        .indirect_export_entries
+        .iter()
+        .chain(&module_record.star_export_entries)
+        .filter_map(|entry| {
+            let used_export = match &entry.import_name {
+                ExportImportName::Name(name) => UsedExport::Name(name.name().to_string()),
+                ExportImportName::All | ExportImportName::AllButDefault => UsedExport::All,
+                ExportImportName::Null => return None,
+            };
+            Some((entry.module_request.as_ref()?.name(), used_export))
+        });
+    let dynamic_imports = module_record
+        .requested_modules
+        .iter()
+        .filter(|(_, requested_modules)| requested_modules.iter().any(RequestedModule::is_dynamic))
+        .map(|(specifier, _)| (specifier, UsedExport::All));
+
+    imports
+        .chain(re_exports)
+        .chain(dynamic_imports)
+        .filter_map(|(specifier, used_export)| {
+            let loaded_module = module_record.loaded_modules.get(specifier)?;
+            Some((loaded_module.resolved_absolute_path.clone(), used_export))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, path::Path, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::DiagnosticService;
+    use serde_json::json;
+
+    use super::{LintService, Runtime};
+    use crate::{rules::RULES, LintOptions, Linter};
+
+    #[test]
+    fn shared_dependency_is_parsed_once() {
+        let cwd = env::current_dir().unwrap().join("fixtures/import/cycles");
+        let paths = ["es6/depth-one.js", "es6/depth-one-reexport.js", "es6/depth-one-dynamic.js"]
+            .map(|path| cwd.join(path).into_boxed_path());
+        let linter = Linter::from_options(LintOptions::default().with_import_plugin(true)).unwrap();
+        let service = LintService::new(cwd.clone().into_boxed_path(), &paths, linter);
+        let diagnostic_service = DiagnosticService::default();
+        service.run(diagnostic_service.sender());
+
+        // `depth-zero.js` is the only dependency of the three files.
+        assert_eq!(service.number_of_dependencies(), 1);
+        let module_map = &service.runtime.module_map;
+        let depth_zero = module_map.get(cwd.join("depth-zero.js").as_path()).unwrap();
+        for path in &paths {
+            let module_record = module_map.get::<Path>(path).unwrap();
+            let loaded = module_record.loaded_modules.get("../depth-zero").unwrap();
+            assert!(Arc::ptr_eq(&loaded, &depth_zero));
+        }
+    }
+
+    #[test]
+    fn preloaded_module_keeps_its_record() {
+        let cwd = env::current_dir().unwrap().join("fixtures/import/cycles");
+        let rule = RULES.iter().find(|rule| rule.name() == "no-unused-modules").unwrap();
+        let rule =
+            rule.read_json(Some(json!([{ "unusedExports": true, "src": [cwd.join("es6")] }])));
+        let linter = Linter::from_options(LintOptions::default().with_import_plugin(true))
+            .unwrap()
+            .with_rules(vec![rule]);
+        // Linted through a relative path, like `oxlint src`
+        let path = Path::new("fixtures/import/cycles/depth-zero.js");
+        let service = LintService::new(cwd.clone().into_boxed_path(), &[path.into()], linter);
+        let diagnostic_service = DiagnosticService::default();
+        service.run(diagnostic_service.sender());
+
+        let module_map = &service.runtime.module_map;
+        assert!(!module_map.contains_key(path));
+        let depth_zero = module_map.get(cwd.join("depth-zero.js").as_path()).unwrap();
+        let depth_one = module_map.get(cwd.join("es6/depth-one.js").as_path()).unwrap();
+        let loaded = depth_one.loaded_modules.get("../depth-zero").unwrap();
+        assert!(Arc::ptr_eq(&loaded, &depth_zero));
+    }
+
+    #[test]
+    fn export_usages_are_collected_per_run() {
+        let cwd = env::temp_dir().join(format!("oxc_linter_export_usages_{}", std::process::id()));
+        fs::create_dir_all(&cwd).unwrap();
+        let cwd = cwd.canonicalize().unwrap();
+        fs::write(cwd.join("a.js"), "import { x } from './b'").unwrap();
+        let source_text = "export const x = 1; export const y = 2";
+        fs::write(cwd.join("b.js"), source_text).unwrap();
+
+        let rule = RULES.iter().find(|rule| rule.name() == "no-unused-modules").unwrap();
+        let rule = rule.read_json(Some(json!([{ "unusedExports": true, "src": [cwd] }])));
+        let linter = Linter::from_options(LintOptions::default().with_import_plugin(true))
+            .unwrap()
+            .with_rules(vec![rule]);
+        let linter = Arc::new(linter);
+        let lint = || {
+            let paths = [cwd.join("b.js").into_boxed_path()];
+            let runtime = Runtime::new(cwd.clone().into_boxed_path(), &paths, Arc::clone(&linter));
+            let service = LintService { runtime: Arc::new(runtime) };
+            let allocator = Allocator::default();
+            let diagnostic_service = DiagnosticService::default();
+            service.run_source(&allocator, source_text, false, diagnostic_service.sender()).len()
+        };
+
+        // `y` is unused
+        assert_eq!(lint(), 1);
+        fs::write(cwd.join("a.js"), "import { x, y } from './b'").unwrap();
+        assert_eq!(lint(), 0);
+
+        fs::remove_dir_all(&cwd).unwrap();
+    }
+}
@@ -55,6 +55,7 @@ impl From<(&str, Option<Value>, Option<Value>, Option<PathBuf>)> for TestCase {
 pub struct Tester {
     rule_name: &'static str,
     rule_path: PathBuf,
+    relative_rule_path: bool,
     expect_pass: Vec<TestCase>,
     expect_fail: Vec<TestCase>,
     expect_fix: Vec<(String, String, Option<Value>)>,
@@ -81,6 +82,7 @@ impl Tester {
         Self {
             rule_name,
             rule_path,
+            relative_rule_path: false,
             expect_pass,
             expect_fail,
             expect_fix: vec![],
@@ -100,6 +102,13 @@ impl Tester {
         self
     }
 
+    /// Lint the rule path through a path relative to the process working directory,
+    /// like the CLI does when given relative paths.
+    pub fn with_relative_rule_path(mut self, yes: bool) -> Self {
+        self.relative_rule_path = yes;
+        self
+    }
+
     pub fn with_import_plugin(mut self, yes: bool) -> Self {
         self.import_plugin = yes;
         self
@@ -200,14 +209,18 @@ impl Tester {
             .unwrap()
             .with_rules(vec![rule])
             .with_settings(lint_settings);
-        let path_to_lint = if self.import_plugin {
-            assert!(path.is_none(), "import plugin does not support path");
-            self.current_working_directory.join(&self.rule_path)
-        } else if let Some(path) = path {
+        let path_to_lint = if let Some(path) = path {
             self.current_working_directory.join(path)
+        } else if self.import_plugin {
+            self.current_working_directory.join(&self.rule_path)
         } else {
             self.rule_path.clone()
         };
+        let path_to_lint = if self.relative_rule_path {
+            path_to_lint.strip_prefix(env::current_dir().unwrap()).unwrap().to_path_buf()
+        } else {
+            path_to_lint
+        };
 
         let lint_service = LintService::from_linter(
             self.current_working_directory.clone(),
@@ -228,7 +241,9 @@ impl Tester {
         }
 
         let diagnostic_path = if self.import_plugin {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
+            path.as_deref().unwrap_or_else(|| {
+                self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
+            })
         } else {
             &self.rule_path
         }
@@ -1,8 +1,9 @@
 mod jest;
 mod nextjs;
 mod node;
+mod path;
 mod react;
 mod react_perf;
 mod unicorn;
 
-pub use self::{jest::*, nextjs::*, node::*, react::*, react_perf::*, unicorn::*};
+pub use self::{jest::*, nextjs::*, node::*, path::*, react::*, react_perf::*, unicorn::*};
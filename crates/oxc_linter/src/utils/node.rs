@@ -65,3 +65,8 @@ pub const NODE_BUILTINS_MODULE: phf::Set<&str> = phf::phf_set![
     "worker_threads",
     "zlib",
 ];
+
+/// `node:` prefixed specifiers always refer to builtins, including prefix-only ones like `node:test`.
+pub fn is_builtin_module(name: &str) -> bool {
+    name.starts_with("node:") || NODE_BUILTINS_MODULE.contains(name)
+}
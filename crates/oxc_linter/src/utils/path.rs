@@ -0,0 +1,30 @@
+use std::{
+    env,
+    path::{Component, Path, PathBuf},
+};
+
+/// Lexically resolves `.` and `..` components.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// The absolute, canonical form of `path`, comparable with resolved module paths.
+///
+/// Linted paths are relative to the current working directory when the CLI is given relative
+/// paths. Paths that don't exist are only made absolute and normalized.
+pub fn canonicalize_path(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| {
+        let cwd = env::current_dir().unwrap_or_default();
+        normalize_path(&cwd.join(path))
+    })
+}
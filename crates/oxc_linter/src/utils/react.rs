@@ -7,8 +7,13 @@ use oxc_ast::{
     AstKind,
 };
 use oxc_semantic::{AstNode, SymbolFlags};
+use oxc_syntax::operator::UnaryOperator;
+use rustc_hash::FxHashMap;
 
-use crate::{ESLintSettings, LintContext};
+use crate::{
+    globals::{INTERACTIVE_ROLES, NON_INTERACTIVE_ELEMENTS, VALID_ARIA_ROLES},
+    ESLintSettings, LintContext,
+};
 
 pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
     if let Some(member_expr) = call_expr.callee.get_member_expr() {
@@ -154,6 +159,43 @@ pub fn is_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningEle
     }
 }
 
+/// Whether `element_type` has a non-interactive implicit role, e.g. `main`, `li` or `h1`.
+/// Elements made interactive by their attributes (`<img usemap>`) are excluded.
+pub fn is_non_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
+    NON_INTERACTIVE_ELEMENTS.contains(element_type)
+        && !is_interactive_element(element_type, jsx_opening_el)
+}
+
+/// Whether `role` is an interactive widget role, e.g. `button` or `menuitem`.
+pub fn is_interactive_role(role: &str) -> bool {
+    INTERACTIVE_ROLES.contains(role)
+}
+
+/// Whether `role` is a valid ARIA role that is not interactive, including `presentation` and `none`.
+pub fn is_non_interactive_role(role: &str) -> bool {
+    VALID_ARIA_ROLES.contains(role) && !INTERACTIVE_ROLES.contains(role)
+}
+
+/// Reads the options of the role conversion rules, an object mapping element names to the
+/// roles they may be given, e.g. `[{ "ul": ["listbox", "menu"] }]`.
+pub fn parse_element_roles(value: &serde_json::Value) -> Option<FxHashMap<String, Vec<String>>> {
+    let config = value.as_array()?.iter().find_map(serde_json::Value::as_object)?;
+    Some(
+        config
+            .iter()
+            .map(|(element, roles)| {
+                let roles = roles
+                    .as_array()
+                    .map(|roles| {
+                        roles.iter().filter_map(|role| role.as_str().map(String::from)).collect()
+                    })
+                    .unwrap_or_default();
+                (element.clone(), roles)
+            })
+            .collect(),
+    )
+}
+
 const PRAGMA: &str = "React";
 const CREATE_CLASS: &str = "createReactClass";
 
@@ -244,6 +286,32 @@ pub fn get_element_type(context: &LintContext, element: &JSXOpeningElement) -> O
     Some(String::from(jsx_a11y.components.get(raw_type).map_or(raw_type, |c| c)))
 }
 
+/// Whether `element` is an `<html>` element, as resolved by [`get_element_type`] from
+/// `settings.jsx-a11y.components` and the polymorphic prop.
+pub fn is_html_element(context: &LintContext, element: &JSXOpeningElement) -> bool {
+    get_element_type(context, element).is_some_and(|element_type| element_type == "html")
+}
+
+/// Whether `element` is an image, merging `settings.jsx-a11y.components` with the image
+/// `components` configured for the rule itself: its type resolved by [`get_element_type`] is
+/// `img` or one of `components`, or its name is one of `components` as written.
+pub fn is_image_element(
+    context: &LintContext,
+    element: &JSXOpeningElement,
+    components: &[String],
+) -> bool {
+    let JSXElementName::Identifier(ident) = &element.name else {
+        return false;
+    };
+    let Some(element_type) = get_element_type(context, element) else {
+        return false;
+    };
+    element_type == "img"
+        || components
+            .iter()
+            .any(|component| *component == element_type || component == ident.name.as_str())
+}
+
 pub fn parse_jsx_value(value: &JSXAttributeValue) -> Result<f64, ()> {
     match value {
         JSXAttributeValue::StringLiteral(str) => str.value.parse().or(Err(())),
@@ -256,8 +324,98 @@ pub fn parse_jsx_value(value: &JSXAttributeValue) -> Result<f64, ()> {
                 tmpl.quasis.first().unwrap().value.raw.parse().or(Err(()))
             }
             Expression::NumberLiteral(num) => Ok(num.value),
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::UnaryNegation =>
+            {
+                match &unary.argument {
+                    Expression::NumberLiteral(num) => Ok(-num.value),
+                    _ => Err(()),
+                }
+            }
             _ => Err(()),
         },
         _ => Err(()),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::{
+        is_interactive_element, is_interactive_role, is_non_interactive_element,
+        is_non_interactive_role,
+    };
+    use crate::globals::NON_INTERACTIVE_ELEMENTS;
+
+    fn with_opening_element(source: &str, f: impl FnOnce(&str, &super::JSXOpeningElement)) {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::default().with_jsx(true)).parse();
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            unreachable!()
+        };
+        let Expression::JSXElement(element) = &stmt.expression else { unreachable!() };
+        let super::JSXElementName::Identifier(ident) = &element.opening_element.name else {
+            unreachable!()
+        };
+        f(ident.name.as_str(), &element.opening_element);
+    }
+
+    #[test]
+    fn test_is_interactive_role() {
+        for role in
+            ["button", "checkbox", "link", "menuitem", "option", "tab", "textbox", "toolbar"]
+        {
+            assert!(is_interactive_role(role), "{role}");
+            assert!(!is_non_interactive_role(role), "{role}");
+        }
+        for role in ["article", "heading", "img", "listitem", "main", "presentation", "none"] {
+            assert!(!is_interactive_role(role), "{role}");
+            assert!(is_non_interactive_role(role), "{role}");
+        }
+        // neither interactive nor a valid non-interactive role
+        for role in ["datepicker", "range", ""] {
+            assert!(!is_interactive_role(role), "{role}");
+            assert!(!is_non_interactive_role(role), "{role}");
+        }
+    }
+
+    #[test]
+    fn test_is_interactive_element() {
+        for source in [
+            "<button />",
+            "<select />",
+            "<textarea />",
+            "<input />",
+            "<input type=\"text\" />",
+            "<a href=\"#\" />",
+            "<audio controls />",
+            "<img usemap=\"#map\" />",
+        ] {
+            with_opening_element(source, |name, element| {
+                assert!(is_interactive_element(name, element), "{source}");
+                assert!(!is_non_interactive_element(name, element), "{source}");
+            });
+        }
+        for source in ["<div />", "<a />", "<input type=\"hidden\" />", "<audio />", "<img />"] {
+            with_opening_element(source, |name, element| {
+                assert!(!is_interactive_element(name, element), "{source}");
+            });
+        }
+        for source in ["<main />", "<li />", "<h1 />", "<ul />", "<article />", "<img />"] {
+            with_opening_element(source, |name, element| {
+                assert!(!is_interactive_element(name, element), "{source}");
+                assert!(is_non_interactive_element(name, element), "{source}");
+            });
+        }
+        // without attributes, no element is classified as both
+        for name in &NON_INTERACTIVE_ELEMENTS {
+            with_opening_element(&format!("<{name} />"), |name, element| {
+                assert!(!is_interactive_element(name, element), "{name}");
+            });
+        }
+    }
+}
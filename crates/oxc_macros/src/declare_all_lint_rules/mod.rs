@@ -108,6 +108,14 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn try_read_json(&self, maybe_value: Option<serde_json::Value>) -> Result<Self, serde_json::Error> {
+                match self {
+                    #(Self::#struct_names(_) => Ok(Self::#struct_names(
+                        maybe_value.map(<#struct_names as Rule>::try_from_configuration).transpose()?.unwrap_or_default(),
+                    ))),*
+                }
+            }
+
             pub fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
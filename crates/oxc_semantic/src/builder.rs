@@ -76,6 +76,9 @@ pub struct SemanticBuilder<'a> {
 
     check_syntax_error: bool,
 
+    /// Add `import()` requests to the module record, this needs a full AST walk.
+    dynamic_imports: bool,
+
     redeclare_variables: RedeclareVariables,
 
     pub cfg: ControlFlowGraph,
@@ -114,6 +117,7 @@ impl<'a> SemanticBuilder<'a> {
             label_builder: LabelBuilder::default(),
             jsdoc: JSDocBuilder::new(source_text, &trivias),
             check_syntax_error: false,
+            dynamic_imports: false,
             redeclare_variables: RedeclareVariables { variables: vec![] },
             cfg: ControlFlowGraph::new(),
             class_table_builder: ClassTableBuilder::new(),
@@ -133,6 +137,14 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Also record dynamic `import()` requests in `build_module_record`,
+    /// which then walks the whole AST instead of the top-level statements only.
+    #[must_use]
+    pub fn with_dynamic_imports(mut self, yes: bool) -> Self {
+        self.dynamic_imports = yes;
+        self
+    }
+
     /// Get the built module record from `build_module_record`
     pub fn module_record(&self) -> Arc<ModuleRecord> {
         Arc::clone(&self.module_record)
@@ -147,6 +159,9 @@ impl<'a> SemanticBuilder<'a> {
     ) -> Self {
         let mut module_record_builder = ModuleRecordBuilder::new(resolved_absolute_path);
         module_record_builder.visit(program);
+        if self.dynamic_imports {
+            module_record_builder.visit_dynamic_imports(program);
+        }
         self.module_record = Arc::new(module_record_builder.build());
         self
     }
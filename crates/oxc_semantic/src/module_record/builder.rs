@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, syntax_directed_operations::BoundNames};
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstKind, Visit};
 use oxc_span::{Atom, GetSpan, Span};
 #[allow(clippy::wildcard_imports)]
 use oxc_syntax::module_record::*;
@@ -63,7 +63,7 @@ impl ModuleRecordBuilder {
                         });
                     });
 
-                    self.add_module_request(&module_request);
+                    self.add_module_request(&module_request, false, false);
                 }
             }
         }
@@ -73,16 +73,27 @@ impl ModuleRecordBuilder {
         self.resolve_export_entries();
     }
 
+    /// Add the `import()` requests of `program` to the requested modules.
+    ///
+    /// `import()` may appear anywhere, so unlike `visit` this walks the whole program.
+    pub fn visit_dynamic_imports(&mut self, program: &Program) {
+        let mut dynamic_imports = DynamicImportCollector::default();
+        dynamic_imports.visit_program(program);
+        for module_request in &dynamic_imports.requests {
+            self.add_module_request(module_request, false, true);
+        }
+    }
+
     pub fn build(self) -> ModuleRecord {
         self.module_record
     }
 
-    fn add_module_request(&mut self, name_span: &NameSpan) {
+    fn add_module_request(&mut self, name_span: &NameSpan, is_type: bool, is_dynamic: bool) {
         self.module_record
             .requested_modules
             .entry(name_span.name().clone())
             .or_default()
-            .push(name_span.span());
+            .push(RequestedModule::new(name_span.span(), is_type, is_dynamic));
     }
 
     fn add_import_entry(&mut self, entry: ImportEntry) {
@@ -210,11 +221,13 @@ impl ModuleRecordBuilder {
     }
 
     fn visit_import_declaration(&mut self, decl: &ImportDeclaration) {
-        if decl.import_kind.is_type() {
-            return;
-        }
         let module_request = NameSpan::new(decl.source.value.clone(), decl.source.span);
+        let mut is_type = decl.import_kind.is_type();
         if let Some(specifiers) = &decl.specifiers {
+            // `import { type x, type y }` only requests types, like `import type { x, y }`
+            if !specifiers.is_empty() && specifiers.iter().all(is_type_specifier) {
+                is_type = true;
+            }
             for specifier in specifiers {
                 let (import_name, local_name) = match specifier {
                     ImportDeclarationSpecifier::ImportSpecifier(specifier) => (
@@ -233,14 +246,17 @@ impl ModuleRecordBuilder {
                         NameSpan::new(specifier.local.name.clone(), specifier.local.span),
                     ),
                 };
-                self.add_import_entry(ImportEntry {
-                    module_request: module_request.clone(),
-                    import_name,
-                    local_name,
-                });
+                let entry =
+                    ImportEntry { module_request: module_request.clone(), import_name, local_name };
+                // Type-only imports are erased, so they are not `[[ImportEntries]]`.
+                if decl.import_kind.is_type() || is_type_specifier(specifier) {
+                    self.module_record.type_import_entries.push(entry);
+                } else {
+                    self.add_import_entry(entry);
+                }
             }
         }
-        self.add_module_request(&module_request);
+        self.add_module_request(&module_request, is_type, false);
     }
 
     fn visit_export_all_declaration(&mut self, decl: &ExportAllDeclaration) {
@@ -263,7 +279,7 @@ impl ModuleRecordBuilder {
         if let Some(exported_name) = &decl.exported {
             self.add_export_binding(exported_name.name().clone(), exported_name.span());
         }
-        self.add_module_request(&module_request);
+        self.add_module_request(&module_request, false, false);
     }
 
     fn visit_export_default_declaration(&mut self, decl: &ExportDefaultDeclaration) {
@@ -309,7 +325,7 @@ impl ModuleRecordBuilder {
             decl.source.as_ref().map(|source| NameSpan::new(source.value.clone(), source.span));
 
         if let Some(module_request) = &module_request {
-            self.add_module_request(module_request);
+            self.add_module_request(module_request, false, false);
         }
 
         if let Some(decl) = &decl.declaration {
@@ -363,3 +379,27 @@ impl ModuleRecordBuilder {
         }
     }
 }
+
+/// `import { type x }`
+fn is_type_specifier(specifier: &ImportDeclarationSpecifier) -> bool {
+    match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => specifier.import_kind.is_type(),
+        _ => false,
+    }
+}
+
+/// Collects `import("...")` expressions whose specifier is a string literal.
+#[derive(Default)]
+struct DynamicImportCollector {
+    requests: Vec<NameSpan>,
+}
+
+impl<'a> Visit<'a> for DynamicImportCollector {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if let AstKind::ImportExpression(expr) = kind {
+            if let Expression::StringLiteral(source) = &expr.source {
+                self.requests.push(NameSpan::new(source.value.clone(), source.span));
+            }
+        }
+    }
+}
@@ -14,12 +14,33 @@ mod module_record_tests {
     use crate::SemanticBuilder;
 
     fn build(source_text: &str) -> Arc<ModuleRecord> {
-        let source_type = SourceType::default().with_module(true);
+        build_with_dynamic_imports(source_text, false)
+    }
+
+    fn build_with_dynamic_imports(source_text: &str, dynamic_imports: bool) -> Arc<ModuleRecord> {
+        build_with_source_type(
+            source_text,
+            SourceType::default().with_module(true),
+            dynamic_imports,
+        )
+    }
+
+    fn build_typescript(source_text: &str) -> Arc<ModuleRecord> {
+        let source_type = SourceType::default().with_module(true).with_typescript(true);
+        build_with_source_type(source_text, source_type, false)
+    }
+
+    fn build_with_source_type(
+        source_text: &str,
+        source_type: SourceType,
+        dynamic_imports: bool,
+    ) -> Arc<ModuleRecord> {
         let allocator = Allocator::default();
         let ret = Parser::new(&allocator, source_text, source_type).parse();
         let program = allocator.alloc(ret.program);
         let semantic_ret = SemanticBuilder::new(source_text, source_type)
             .with_trivias(ret.trivias)
+            .with_dynamic_imports(dynamic_imports)
             .build_module_record(PathBuf::new(), program)
             .build(program);
         Arc::clone(&semantic_ret.semantic.module_record)
@@ -76,6 +97,49 @@ mod module_record_tests {
         assert_eq!(module_record.import_entries[0], import_entry);
     }
 
+    #[test]
+    fn import_type_specifier() {
+        let module_record =
+            build_typescript("import type { x } from 'mod'; import { type y, z } from 'mod'");
+        let import_entry = ImportEntry {
+            module_request: NameSpan::new("mod".into(), Span::new(56, 61)),
+            import_name: ImportImportName::Name(NameSpan::new("z".into(), Span::new(47, 48))),
+            local_name: NameSpan::new("z".into(), Span::new(47, 48)),
+        };
+        assert_eq!(module_record.import_entries.len(), 1);
+        assert_eq!(module_record.import_entries[0], import_entry);
+    }
+
+    #[test]
+    fn import_inline_type_specifier() {
+        let module_record = build_typescript("import { type a, b } from 'mod'");
+        let import_entry = ImportEntry {
+            module_request: NameSpan::new("mod".into(), Span::new(26, 31)),
+            import_name: ImportImportName::Name(NameSpan::new("b".into(), Span::new(17, 18))),
+            local_name: NameSpan::new("b".into(), Span::new(17, 18)),
+        };
+        assert_eq!(module_record.import_entries, vec![import_entry]);
+        let type_import_entry = ImportEntry {
+            module_request: NameSpan::new("mod".into(), Span::new(26, 31)),
+            import_name: ImportImportName::Name(NameSpan::new("a".into(), Span::new(14, 15))),
+            local_name: NameSpan::new("a".into(), Span::new(14, 15)),
+        };
+        assert_eq!(module_record.type_import_entries, vec![type_import_entry]);
+        // `b` is a value, so the module is still requested at runtime
+        assert_eq!(
+            module_record.requested_modules["mod"],
+            vec![RequestedModule::new(Span::new(26, 31), false, false)]
+        );
+
+        let module_record = build_typescript("import { type a, type b } from 'mod'");
+        assert!(module_record.import_entries.is_empty());
+        assert_eq!(module_record.type_import_entries.len(), 2);
+        assert_eq!(
+            module_record.requested_modules["mod"],
+            vec![RequestedModule::new(Span::new(31, 36), true, false)]
+        );
+    }
+
     #[test]
     fn import_without_binding() {
         let module_record = build("import 'mod'");
@@ -244,4 +308,28 @@ mod module_record_tests {
         assert_eq!(module_record.local_export_entries.len(), 1);
         assert_eq!(module_record.local_export_entries[0], export_entry);
     }
+
+    #[test]
+    fn requested_modules_dynamic() {
+        let source_text = "import v from 'mod'; function f() { import('mod'); import('lazy') }";
+        let module_record = build(source_text);
+        assert_eq!(
+            module_record.requested_modules["mod"],
+            vec![RequestedModule::new(Span::new(14, 19), false, false)]
+        );
+        assert!(!module_record.requested_modules.contains_key("lazy"));
+
+        let module_record = build_with_dynamic_imports(source_text, true);
+        assert_eq!(
+            module_record.requested_modules["mod"],
+            vec![
+                RequestedModule::new(Span::new(14, 19), false, false),
+                RequestedModule::new(Span::new(43, 48), false, true)
+            ]
+        );
+        assert_eq!(
+            module_record.requested_modules["lazy"],
+            vec![RequestedModule::new(Span::new(58, 64), false, true)]
+        );
+    }
 }
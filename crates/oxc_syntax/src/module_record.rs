@@ -25,8 +25,9 @@ pub struct ModuleRecord {
     ///   import ImportClause FromClause
     ///   import ModuleSpecifier
     ///   export ExportFromClause FromClause
+    ///   import(ModuleSpecifier) (non-standard, recorded as dynamic requests)
     /// Keyed by ModuleSpecifier, valued by all node occurrences
-    pub requested_modules: IndexMap<Atom, Vec<Span>, BuildHasherDefault<FxHasher>>,
+    pub requested_modules: IndexMap<Atom, Vec<RequestedModule>, BuildHasherDefault<FxHasher>>,
 
     /// `[[LoadedModules]]`
     ///
@@ -39,6 +40,10 @@ pub struct ModuleRecord {
     /// A List of ImportEntry records derived from the code of this module
     pub import_entries: Vec<ImportEntry>,
 
+    /// ImportEntry records of TypeScript type-only imports, `import type { x }` and
+    /// `import { type x }`, which are erased at compile time and not part of `[[ImportEntries]]`.
+    pub type_import_entries: Vec<ImportEntry>,
+
     /// `[[LocalExportEntries]]`
     ///
     /// A List of ExportEntry records derived from the code of this module
@@ -92,6 +97,35 @@ impl NameSpan {
     }
 }
 
+/// A single occurrence of a ModuleSpecifier in `[[RequestedModules]]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestedModule {
+    span: Span,
+    is_type: bool,
+    is_dynamic: bool,
+}
+
+impl RequestedModule {
+    pub fn new(span: Span, is_type: bool, is_dynamic: bool) -> Self {
+        Self { span, is_type, is_dynamic }
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// `import type { x }`, or `import { type x }` where every specifier is a type,
+    /// which is erased at compile time
+    pub fn is_type(&self) -> bool {
+        self.is_type
+    }
+
+    /// `import("...")`, which is loaded lazily and not part of the static module graph
+    pub fn is_dynamic(&self) -> bool {
+        self.is_dynamic
+    }
+}
+
 /// [`ImportEntry`](https://tc39.es/ecma262/#importentry-record)
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ImportEntry {